@@ -170,13 +170,14 @@ impl Build {
 
 		let core = Core::new(project, self.watch)?;
 
-		core.build(&path, xml)?;
+		let hash = core.build(&path, xml)?;
 
 		argon_info!(
 			"Successfully built project: {} to: {}",
 			project_path.to_string().bold(),
 			path.to_string().bold()
 		);
+		argon_info!("Content hash: {}", hash.bold());
 
 		if let Some(path) = &sourcemap_path {
 			core.sourcemap(Some(path.clone()), false)?;
@@ -197,7 +198,7 @@ impl Build {
 					.spawn()?;
 			}
 
-			sessions::add(self.session, None, None, process::id(), config.run_async)?;
+			sessions::add(self.session, None, None, None, process::id(), config.run_async)?;
 
 			argon_info!("Watching for changes..");
 
@@ -208,7 +209,9 @@ impl Build {
 				let _message = queue.get_change(0).unwrap();
 
 				info!("Rebuilding project..");
-				core.build(&path, xml)?;
+				let hash = core.build(&path, xml)?;
+
+				info!("Content hash: {}", hash);
 
 				if let Some(path) = &sourcemap_path {
 					info!("Regenerating sourcemap..");
@@ -4,7 +4,7 @@ use reqwest::{blocking::Client, header::CONTENT_TYPE};
 use serde::Serialize;
 use std::{fs, path::MAIN_SEPARATOR};
 
-use crate::{argon_error, argon_info, sessions};
+use crate::{argon_error, argon_info, server::TOKEN_HEADER, sessions};
 
 /// Execute Luau code in Roblox Studio (requires running session)
 #[derive(Parser)]
@@ -65,11 +65,16 @@ impl Exec {
 					},
 				})?;
 
-				let response = Client::default()
+				let mut request = Client::default()
 					.post(url)
 					.header(CONTENT_TYPE, "application/msgpack")
-					.body(body)
-					.send();
+					.body(body);
+
+				if let Some(token) = sessions::get_token(&address)? {
+					request = request.header(TOKEN_HEADER, token);
+				}
+
+				let response = request.send();
 
 				match response {
 					Ok(_) => argon_info!("Code executed successfully!"),
@@ -13,7 +13,9 @@ mod debug;
 mod doc;
 mod exec;
 mod init;
+mod package;  // New for Fluxo
 mod plugin;
+mod promote;  // New for Fluxo
 mod publish;  // New for Fluxo
 mod serve;
 mod sourcemap;
@@ -128,6 +130,8 @@ impl Cli {
 			Commands::Validate(command) => command.main(),
 			Commands::Sync(command) => command.main(),
 			Commands::Publish(command) => command.main(),
+			Commands::Package(command) => command.main(),
+			Commands::Promote(command) => command.main(),
 			Commands::Serve(command) => command.main(),
 			Commands::Stop(command) => command.main(),
 			Commands::Studio(command) => command.main(),
@@ -150,6 +154,8 @@ pub enum Commands {
 	Validate(validate::Validate), // New command for plugin validation
 	Sync(sync::Sync),            // New command for syncing to Studio
 	Publish(publish::Publish),   // New command for publishing plugins
+	Package(package::Package),   // New command for packaging plugins
+	Promote(promote::Promote),   // New command for promoting releases
 	Serve(serve::Serve),
 	Stop(stop::Stop),
 	Studio(studio::Studio),
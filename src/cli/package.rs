@@ -0,0 +1,91 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+use crate::{
+	config::Config,
+	ext::PathExt,
+	plugin::metadata::PluginMetadata,
+	project::{self, Project},
+	publish::package,
+};
+
+/// Package plugin into a zip bundle for review and archival
+#[derive(Parser)]
+pub struct Package {
+	/// Project path
+	#[arg()]
+	project: Option<PathBuf>,
+
+	/// Output path, defaults to <name>-<version>.zip in the project directory
+	#[arg(short, long)]
+	output: Option<PathBuf>,
+
+	/// Verify integrity of an existing bundle instead of creating one
+	#[arg(long, value_name = "ZIP", conflicts_with_all = ["project", "output"])]
+	verify: Option<PathBuf>,
+}
+
+impl Package {
+	pub fn main(self) -> Result<()> {
+		if let Some(path) = &self.verify {
+			return Self::verify(path);
+		}
+
+		let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
+		let workspace_dir = project_path.get_parent();
+
+		Config::load_workspace(workspace_dir);
+
+		println!("{}", "📦 Packaging plugin...".blue().bold());
+
+		let output = match self.output {
+			Some(output) => output,
+			None => {
+				let name = Project::load(&project_path)?.name;
+				let version = PluginMetadata::load(workspace_dir)?.version;
+
+				workspace_dir.join(format!("{}-{}.zip", name, version))
+			}
+		};
+
+		let manifest = package::create(&project_path, &output)?;
+
+		for (name, checksum) in &manifest.files {
+			println!("  {} {} {}", "•".blue(), name, checksum[..12].dimmed());
+		}
+
+		println!(
+			"\n{} {}",
+			"✅ Plugin packaged to".green().bold(),
+			output.to_string().bold()
+		);
+
+		Ok(())
+	}
+
+	fn verify(path: &Path) -> Result<()> {
+		println!("{}", "🔍 Verifying bundle...".blue().bold());
+
+		let (manifest, problems) = package::verify(path)?;
+
+		if !problems.is_empty() {
+			for problem in &problems {
+				println!("  {} {}", "•".red(), problem);
+			}
+
+			bail!("Bundle {} is corrupted", path.to_string());
+		}
+
+		println!(
+			"{} {} {} ({} files)",
+			"✅ Bundle is intact:".green().bold(),
+			manifest.name,
+			manifest.version,
+			manifest.files.len()
+		);
+
+		Ok(())
+	}
+}
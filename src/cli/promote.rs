@@ -0,0 +1,182 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+use colored::Colorize;
+use semver::Version;
+use std::path::PathBuf;
+
+use super::publish::{self, Target};
+use crate::{
+	config::Config,
+	ext::{PathExt, ResultExt},
+	logger,
+	plugin::{config::PluginConfig, metadata::PluginMetadata, team::Permission},
+	project,
+	publish::{
+		cloud::{self, OpenCloud, Upload},
+		ledger::{self, Ledger, ARTIFACTS_DIR},
+		payload::Payload,
+		Artifact,
+	},
+	sessions,
+};
+
+/// Publish the last release of one channel to another without rebuilding
+#[derive(Parser)]
+pub struct Promote {
+	/// Channel to take the release from
+	#[arg()]
+	from: String,
+
+	/// Channel to publish the release to
+	#[arg()]
+	to: String,
+
+	/// Project path
+	#[arg()]
+	project: Option<PathBuf>,
+
+	/// Server host name, defaults to the last `fluxo serve` session
+	#[arg(short = 'H', long)]
+	host: Option<String>,
+
+	/// Server port, defaults to the last `fluxo serve` session
+	#[arg(short, long)]
+	port: Option<u16>,
+
+	/// Where to publish: studio, cloud
+	#[arg(long, default_value = "studio", hide_possible_values = true)]
+	target: Target,
+
+	/// Open Cloud API base URL, overrides publishing.apiUrl
+	#[arg(long, value_name = "URL")]
+	api_url: Option<String>,
+
+	/// Act as this team member, requires the FLUXO_TEAM_TOKEN environment variable
+	#[arg(long = "as", value_name = "MEMBER")]
+	as_member: Option<String>,
+}
+
+impl Promote {
+	pub fn main(self) -> Result<()> {
+		let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
+		let workspace_dir = project_path.get_parent();
+
+		Config::load_workspace(workspace_dir);
+
+		let config = PluginConfig::load(workspace_dir)?;
+
+		config
+			.team
+			.check(workspace_dir, self.as_member.as_deref(), Permission::Publish)?;
+
+		if self.from == self.to {
+			bail!("Cannot promote channel {} to itself", self.from.bold());
+		}
+
+		config.publishing.channel(&self.from)?;
+		let channel = config.publishing.channel(&self.to)?;
+
+		let mut ledger = Ledger::load(workspace_dir)?;
+
+		let Some(source) = ledger.last_in(&self.from).cloned() else {
+			bail!("Nothing was published to the {} channel yet", self.from.bold());
+		};
+
+		// Builds are reproducible, so the current sources can stand in for a missing artifact
+		let data = match ledger::load_artifact(workspace_dir, &source.hash) {
+			Some(data) => data,
+			None => {
+				let artifact = Artifact::build(&project_path)?;
+
+				if artifact.hash != source.hash {
+					bail!(
+						"Plugin {} is missing from {} and the current sources build a different one, check out {} to promote it",
+						&source.hash[..12],
+						ARTIFACTS_DIR,
+						source.commit.as_deref().unwrap_or("the published commit")
+					);
+				}
+
+				artifact.data
+			}
+		};
+
+		let version = Version::parse(&source.version)
+			.with_desc(|| format!("Published version '{}' is not a valid semantic version", source.version))?;
+
+		let mut metadata = PluginMetadata::load(workspace_dir)?;
+		metadata.version = channel.version(&version)?.to_string();
+
+		let metadata = channel.apply(&metadata)?;
+
+		println!(
+			"{} {} {} {} {} {}",
+			"🚀 Promoting".blue().bold(),
+			self.from.bold(),
+			source.version,
+			"to".blue().bold(),
+			self.to.bold(),
+			metadata.version
+		);
+		println!("{}: {}", "Name".cyan().bold(), metadata.name);
+		println!("{}: {}", "Hash".cyan().bold(), source.hash);
+
+		if !logger::prompt("Do you want to publish this release?", true) {
+			println!("{}", "Promotion cancelled.".yellow());
+			return Ok(());
+		}
+
+		let mut entry = source.promote(&metadata, &self.to);
+
+		if self.target == Target::Cloud {
+			let api_url = self
+				.api_url
+				.as_deref()
+				.or(config.publishing.api_url.as_deref())
+				.unwrap_or(cloud::DEFAULT_API_URL);
+
+			let asset_id = channel.asset_id.or(config.publishing.asset_id);
+
+			let asset = OpenCloud::from_env()?.with_api_url(api_url).upload(&Upload {
+				asset_id,
+				creator: config.publishing.creator,
+				name: &metadata.name,
+				description: &metadata.description,
+				data: &data,
+			})?;
+
+			entry.asset = Some(asset);
+
+			ledger.push(entry);
+			ledger.save()?;
+
+			publish::print_asset(asset, asset_id, Some(&self.to));
+
+			return Ok(());
+		}
+
+		let address = sessions::get_address(self.host.clone(), self.port)?;
+
+		if !publish::check_studio_connection(&address)? {
+			bail!("Studio connection failed, make sure `fluxo serve` is running and Studio is open");
+		}
+
+		// Studio publishes what it has synced, the server refuses the request unless it builds to `source.hash`
+		let mut payload = Payload::from(&source);
+
+		payload.metadata = metadata;
+		payload.channel = Some(self.to.clone());
+		payload.size = data.len();
+
+		if !publish::send_publish_request(&address, &payload)? {
+			bail!("Studio did not accept the publish request");
+		}
+
+		ledger.push(entry);
+		ledger.save()?;
+
+		println!("{}", "✅ Promotion sent to Studio!".green().bold());
+
+		Ok(())
+	}
+}
@@ -1,13 +1,35 @@
-use anyhow::Result;
-use clap::Parser;
-use std::path::PathBuf;
+use anyhow::{bail, Result};
+use clap::{Parser, ValueEnum};
+use std::{fs, path::{Path, PathBuf}};
 use colored::Colorize;
 use serde_json;
 
 use crate::{
     config::Config,
-    ext::PathExt,
+    ext::{PathExt, ResultExt},
+    git,
+    logger::Table,
+    plugin::{
+        changelog,
+        channel::Channel,
+        config::PluginConfig,
+        icon::{self, Icon},
+        metadata::PluginMetadata,
+        META_FILE,
+        team::Permission,
+        version::{Bump, DEFAULT_PRERELEASE},
+    },
     project,
+    publish::{
+        cloud::{self, Asset, OpenCloud, Upload},
+        ledger::{self, Entry, FileChange, Ledger},
+        notes,
+        payload::{self, Payload},
+        Artifact,
+    },
+    server::TOKEN_HEADER,
+    sessions,
+    validation::{format, validator::Validator},
 };
 
 /// Trigger Studio-side publishing flow
@@ -18,64 +40,168 @@ pub struct Publish {
     project: Option<PathBuf>,
     
     /// Version to publish (semver format)
-    #[arg(short, long)]
+    #[arg(long, conflicts_with = "bump")]
     version: Option<String>,
     
+    /// Bump the version: major, minor, patch, prerelease
+    #[arg(long, value_name = "LEVEL", hide_possible_values = true)]
+    bump: Option<Bump>,
+    
     /// Release notes
     #[arg(short, long)]
     notes: Option<String>,
     
+    /// Generate release notes from commits since the last publish or tag
+    #[arg(long, conflicts_with = "notes")]
+    notes_from_git: bool,
+    
     /// Skip confirmation prompt
     #[arg(short, long)]
     yes: bool,
     
-    /// Port to use for communication with Studio
-    #[arg(short, long, default_value = "8080")]
-    port: u16,
+    /// Server host name, defaults to the last `fluxo serve` session
+    #[arg(short = 'H', long)]
+    host: Option<String>,
+    
+    /// Server port, defaults to the last `fluxo serve` session
+    #[arg(short, long)]
+    port: Option<u16>,
+    
+    /// Release channel to publish to, defined in publishing.channels
+    #[arg(long, value_name = "NAME")]
+    channel: Option<String>,
+    
+    /// Where to publish: studio, cloud
+    #[arg(long, default_value = "studio", hide_possible_values = true)]
+    target: Target,
+    
+    /// Act as this team member, requires the FLUXO_TEAM_TOKEN environment variable
+    #[arg(long = "as", value_name = "MEMBER")]
+    as_member: Option<String>,
+    
+    /// Open Cloud API base URL, overrides publishing.apiUrl
+    #[arg(long, value_name = "URL")]
+    api_url: Option<String>,
+    
+    /// List previous publishes of this plugin
+    #[arg(long)]
+    history: bool,
+    
+    /// Show files changed since the given published version
+    #[arg(long, value_name = "VERSION")]
+    diff: Option<String>,
+    
+    /// Build and write the payload without sending it anywhere
+    #[arg(long)]
+    dry_run: bool,
+    
+    /// Previous payload to compare the dry run with, defaults to the last publish
+    #[arg(long, value_name = "PAYLOAD", requires = "dry_run")]
+    against: Option<PathBuf>,
+    
+    /// Directory to write the dry run to, defaults to .fluxo/dry-run
+    #[arg(long, value_name = "DIR", requires = "dry_run")]
+    output: Option<PathBuf>,
 }
 
 impl Publish {
-    pub fn main(self) -> Result<()> {
+    pub fn main(mut self) -> Result<()> {
+        let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
+        let workspace_dir = project_path.get_parent();
+        
+        Config::load_workspace(workspace_dir);
+        
+        if self.history {
+            return self.show_history(workspace_dir);
+        }
+        
+        if let Some(version) = &self.diff {
+            return self.show_diff(&project_path, version);
+        }
+        
         println!("{}", "🚀 Starting plugin publish process...".blue().bold());
         
-        let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
+        let config = PluginConfig::load(workspace_dir)?;
+        
+        // Dry runs do not publish nor write anything back
+        if !self.dry_run {
+            let as_member = self.as_member.as_deref();
+            
+            config.team.check(workspace_dir, as_member, Permission::Publish)?;
+            
+            if self.version.is_some() || self.bump.is_some() {
+                config.team.check(workspace_dir, as_member, Permission::EditMetadata)?;
+            }
+        }
         
-        Config::load_workspace(project_path.get_parent());
+        let channel = match &self.channel {
+            Some(name) => Some(config.publishing.channel(name)?),
+            None => None,
+        };
         
         // Step 1: Validate the plugin
         println!("{}", "Step 1: Validating plugin...".cyan());
-        let validate_cmd = super::validate::Validate {
-            project: self.project.clone(),
-            skip_metadata: false,
-            skip_api_check: false,
-            skip_structure: false,
-            verbose: false,
-        };
+        let report = Validator::new().run(workspace_dir);
+        print!("{}", format::to_text(&report, false));
         
-        if let Err(e) = validate_cmd.main() {
+        if !report.success {
             println!("{}", "❌ Validation failed. Fix errors before publishing.".red());
-            return Err(e);
+            bail!("Validation failed with {} errors", report.summary.errors);
         }
         
-        // Step 2: Check Studio connection
-        println!("{}", "Step 2: Checking Studio connection...".cyan());
-        if !self.check_studio_connection()? {
-            println!("{}", "❌ Could not connect to Studio. Make sure:".red());
-            println!("  {} Roblox Studio is running", "•".red());
-            println!("  {} Fluxo Studio plugin is installed and enabled", "•".red());
-            return Err(anyhow::anyhow!("Studio connection failed"));
-        }
+        // Step 2: Check Open Cloud credentials or Studio connection
+        let cloud = if self.dry_run {
+            println!("{}", "Step 2: Dry run, not contacting Studio or Open Cloud".cyan());
+            None
+        } else if self.target == Target::Cloud {
+            println!("{}", "Step 2: Checking Open Cloud credentials...".cyan());
+            
+            let api_url = self.api_url.as_deref()
+                .or(config.publishing.api_url.as_deref())
+                .unwrap_or(cloud::DEFAULT_API_URL);
+            
+            Some(OpenCloud::from_env()?.with_api_url(api_url))
+        } else {
+            println!("{}", "Step 2: Checking Studio connection...".cyan());
+            if !check_studio_connection(&self.address()?)? {
+                println!("{}", "❌ Could not connect to Studio. Make sure:".red());
+                println!("  {} Roblox Studio is running", "•".red());
+                println!("  {} Fluxo Studio plugin is installed and enabled", "•".red());
+                return Err(anyhow::anyhow!("Studio connection failed"));
+            }
+            
+            None
+        };
         
         // Step 3: Load and prepare metadata
         println!("{}", "Step 3: Preparing metadata...".cyan());
-        let mut metadata = self.load_metadata(&project_path)?;
+        let mut metadata = PluginMetadata::load(workspace_dir)?;
+        let version = self.next_version(&metadata, channel)?;
         
-        if let Some(version) = &self.version {
-            metadata["version"] = serde_json::Value::String(version.clone());
+        if let Some(version) = &version {
+            metadata.version = version.clone();
+        }
+        
+        // Channel overrides are published but never written back
+        let mut published = match channel {
+            Some(channel) => channel.apply(&metadata)?,
+            None => metadata.clone(),
+        };
+        
+        if published.icon.is_none() {
+            published.icon = icon::locate(workspace_dir, None);
+        }
+        
+        if self.notes_from_git {
+            self.notes = Self::notes_from_git(workspace_dir)?;
         }
         
         // Step 4: Show publish preview
-        self.show_publish_preview(&metadata)?;
+        self.show_publish_preview(&published)?;
+        
+        if self.dry_run {
+            return self.dry_run(&project_path, &published);
+        }
         
         // Step 5: Confirmation
         if !self.yes && !self.confirm_publish()? {
@@ -83,9 +209,78 @@ impl Publish {
             return Ok(());
         }
         
-        // Step 6: Send publish request to Studio
+        // Written back before the build so the artifact contains the new version
+        let rollback = match &version {
+            Some(version) => Some(self.release(workspace_dir, &metadata, version)?),
+            None => None,
+        };
+        
+        let result = self.send(&project_path, &config, &published, channel, cloud);
+        
+        if let (Err(_), Some(rollback)) = (&result, rollback) {
+            rollback.restore()?;
+            println!("{}", "↩️  Reverted version and changelog, nothing was published".yellow());
+        }
+        
+        result
+    }
+    
+    /// Build the plugin, publish it and record it in the ledger
+    fn send(
+        &self,
+        project_path: &Path,
+        config: &PluginConfig,
+        published: &PluginMetadata,
+        channel: Option<&Channel>,
+        cloud: Option<OpenCloud>,
+    ) -> Result<()> {
+        let workspace_dir = project_path.get_parent();
+        
+        write_icons(workspace_dir, published, &workspace_dir.join(icon::ICONS_DIR))?;
+        
+        let artifact = Artifact::build(project_path)?;
+        let mut payload = Payload::new(&artifact, published, self.notes.clone());
+        payload.channel = self.channel.clone();
+        
+        let mut ledger = Ledger::load(workspace_dir)?;
+        let commit = git::head_commit(workspace_dir)?;
+        
+        let mut entry = Entry::new(&artifact, published, self.notes.clone(), commit);
+        entry.channel = self.channel.clone();
+        
+        let asset_id = channel.and_then(|channel| channel.asset_id).or(config.publishing.asset_id);
+        
+        // Step 6: Upload to Open Cloud or send publish request to Studio
+        if let Some(cloud) = cloud {
+            println!("{}", "Step 6: Uploading plugin to Open Cloud...".cyan());
+            
+            let asset = cloud.upload(&Upload {
+                asset_id,
+                creator: config.publishing.creator,
+                name: &published.name,
+                description: &published.description,
+                data: &artifact.data,
+            })?;
+            
+            entry.asset = Some(asset);
+            
+            ledger::store_artifact(workspace_dir, &artifact)?;
+            ledger.push(entry);
+            ledger.save()?;
+            
+            print_asset(asset, asset_id, self.channel.as_deref());
+            
+            return Ok(());
+        }
+        
         println!("{}", "Step 6: Sending publish request to Studio...".cyan());
-        self.send_publish_request(&metadata)?;
+        if !send_publish_request(&self.address()?, &payload)? {
+            bail!("Studio did not accept the publish request");
+        }
+        
+        ledger::store_artifact(workspace_dir, &artifact)?;
+        ledger.push(entry);
+        ledger.save()?;
         
         println!("{}", "✅ Publish request sent to Studio!".green().bold());
         println!("{}", "The Studio plugin will now handle the actual publishing process.".green());
@@ -94,57 +289,135 @@ impl Publish {
         Ok(())
     }
     
-    fn check_studio_connection(&self) -> Result<bool> {
-        println!("{}", "🔍 Connecting to Studio...".cyan());
-        
-        let url = format!("http://localhost:{}/health", self.port + 1000); // Fluxo HTTP port
-        
-        match reqwest::blocking::get(&url) {
-            Ok(response) => {
-                if response.status().is_success() {
-                    println!("{}", "✅ Studio plugin connection verified".green());
-                    Ok(true)
-                } else {
-                    println!("{}", "❌ Studio plugin not responding".red());
-                    Ok(false)
+    /// Address of the server Studio is connected to
+    fn address(&self) -> Result<String> {
+        sessions::get_address(self.host.clone(), self.port)
+    }
+    
+    /// Returns the version to publish if it differs from the one in `plugin.meta.json`
+    fn next_version(&self, metadata: &PluginMetadata, channel: Option<&Channel>) -> Result<Option<String>> {
+        let (version, bumped) = if let Some(version) = &self.version {
+            let version = semver::Version::parse(version.trim())
+                .with_desc(|| format!("Version '{}' is not a valid semantic version", version))?;
+            
+            (version, false)
+        } else {
+            let current = semver::Version::parse(metadata.version.trim())
+                .with_desc(|| format!("Cannot bump invalid version '{}'", metadata.version));
+            
+            match self.bump {
+                Some(bump) => {
+                    let prerelease = channel.and_then(|channel| channel.prerelease.as_deref()).unwrap_or(DEFAULT_PRERELEASE);
+                    (bump.apply_with(&current?, prerelease)?, true)
+                }
+                None => {
+                    // Channels still need a valid version even if it does not change
+                    if let (Some(channel), Ok(current)) = (channel, &current) {
+                        self.check_channel_version(channel, current)?;
+                    }
+                    
+                    return Ok(None);
                 }
-            },
-            Err(_) => {
-                println!("{}", "❌ Cannot reach Studio plugin".red());
-                Ok(false)
             }
+        };
+        
+        let Some(channel) = channel else {
+            return Ok(Some(version.to_string()));
+        };
+        
+        // Bumped stable versions become the first prerelease of the channel
+        let version = if bumped && version.pre.is_empty() {
+            channel.version(&version)?
+        } else {
+            version
+        };
+        
+        self.check_channel_version(channel, &version)?;
+        
+        Ok(Some(version.to_string()))
+    }
+    
+    fn check_channel_version(&self, channel: &Channel, version: &semver::Version) -> Result<()> {
+        if channel.accepts(version) {
+            return Ok(());
+        }
+        
+        let name = self.channel.as_deref().unwrap_or_default();
+        
+        match &channel.prerelease {
+            Some(tag) => bail!(
+                "Version {} cannot be published to the {} channel, it needs a '{}' prerelease, use --bump prerelease",
+                version, name.bold(), tag
+            ),
+            None => bail!(
+                "Version {} cannot be published to the {} channel, it only accepts stable versions, use --bump or fluxo promote",
+                version, name.bold()
+            ),
         }
     }
     
-    fn load_metadata(&self, project_path: &std::path::Path) -> Result<serde_json::Value> {
-        let meta_file = project_path.join("plugin.meta.json");
+    /// Returns release notes generated from commits since the last publish or tag
+    fn notes_from_git(workspace_dir: &Path) -> Result<Option<String>> {
+        if !git::is_repo(workspace_dir) {
+            bail!("Cannot generate release notes, {} is not a Git repository", workspace_dir.to_string());
+        }
         
-        if meta_file.exists() {
-            let meta_content = std::fs::read_to_string(meta_file)?;
-            Ok(serde_json::from_str(&meta_content)?)
+        let ledger = Ledger::load(workspace_dir)?;
+        let published = ledger.last().and_then(|entry| entry.commit.as_deref());
+        
+        let base = notes::base(workspace_dir, published)?;
+        let commits = git::commits(workspace_dir, base.as_deref())?;
+        
+        let since = match &base {
+            Some(base) if Some(base.as_str()) == published => format!("since {}", short_hash(base)),
+            Some(base) => format!("since {}", base),
+            None => String::from("in the repository"),
+        };
+        
+        if commits.is_empty() {
+            println!("{}", format!("No commits {}, publishing without release notes", since).yellow());
         } else {
-            return Err(anyhow::anyhow!("plugin.meta.json not found"));
+            let count = if commits.len() == 1 { String::from("1 commit") } else { format!("{} commits", commits.len()) };
+            println!("{} {} {}", "📝 Generated release notes from".green(), count, since.green());
         }
+        
+        Ok(notes::generate(&commits))
+    }
+    
+    /// Write the new version back to `plugin.meta.json` and add its section to the changelog,
+    /// returns the previous contents of both files to restore if publishing fails
+    fn release(&self, workspace_dir: &Path, metadata: &PluginMetadata, version: &str) -> Result<Rollback> {
+        let date = chrono::Local::now().date_naive();
+        let rollback = Rollback::capture(&[
+            workspace_dir.join(META_FILE),
+            workspace_dir.join(changelog::CHANGELOG_FILE),
+        ])?;
+        
+        changelog::update(workspace_dir, version, date, self.notes.as_deref())?;
+        metadata.save(workspace_dir)?;
+        
+        println!("{} {}", "📝 Updated version and changelog to".green(), version.bold());
+        
+        Ok(rollback)
     }
     
-    fn show_publish_preview(&self, metadata: &serde_json::Value) -> Result<()> {
+    fn show_publish_preview(&self, metadata: &PluginMetadata) -> Result<()> {
         println!("\n{}", "📋 Publish Preview:".blue().bold());
         println!("{}", "─".repeat(50));
         
-        if let Some(name) = metadata.get("name").and_then(|v| v.as_str()) {
-            println!("{}: {}", "Name".cyan().bold(), name);
-        }
+        println!("{}: {}", "Name".cyan().bold(), metadata.name);
+        println!("{}: {}", "Version".cyan().bold(), metadata.version);
         
-        if let Some(version) = metadata.get("version").and_then(|v| v.as_str()) {
-            println!("{}: {}", "Version".cyan().bold(), version);
+        if !metadata.description.is_empty() {
+            println!("{}: {}", "Description".cyan().bold(), metadata.description);
         }
         
-        if let Some(description) = metadata.get("description").and_then(|v| v.as_str()) {
-            println!("{}: {}", "Description".cyan().bold(), description);
+        if let Some(author) = &metadata.author {
+            println!("{}: {}", "Author".cyan().bold(), author);
         }
         
-        if let Some(author) = metadata.get("author").and_then(|v| v.as_str()) {
-            println!("{}: {}", "Author".cyan().bold(), author);
+        if let Some(icon) = &metadata.icon {
+            println!("{}: {}", "Icon".cyan().bold(), icon);
         }
         
         if let Some(notes) = &self.notes {
@@ -169,44 +442,292 @@ impl Publish {
         Ok(input == "y" || input == "yes")
     }
     
-    fn send_publish_request(&self, metadata: &serde_json::Value) -> Result<()> {
-        println!("{}", "📤 Sending publish request to Studio...".cyan());
-        
-        let publish_data = serde_json::json!({
-            "metadata": metadata,
-            "notes": self.notes
-        });
-        
-        let client = reqwest::blocking::Client::new();
-        let url = format!("http://localhost:{}/publish", self.port + 1000);
-        
-        match client.post(&url)
-            .json(&publish_data)
-            .send() 
-        {
-            Ok(response) => {
-                if response.status().is_success() {
-                    if let Ok(result) = response.json::<serde_json::Value>() {
-                        if result["success"].as_bool().unwrap_or(false) {
-                            println!("{}", "✅ Publish request sent successfully!".green());
-                            println!("{}", "🎯 Studio will show the publish confirmation dialog".cyan());
-                        } else {
-                            let error = result["error"].as_str().unwrap_or("Unknown error");
-                            println!("{} Publish failed: {}", "❌".red(), error);
-                        }
-                    } else {
+    fn show_history(&self, workspace_dir: &Path) -> Result<()> {
+        let ledger = Ledger::load(workspace_dir)?;
+        
+        if ledger.entries().is_empty() {
+            println!("{}", "This plugin has not been published yet".yellow());
+            return Ok(());
+        }
+        
+        let mut table = Table::new();
+        table.set_header(vec!["Version", "Channel", "Published", "Commit", "Hash", "Asset", "Notes"]);
+        
+        for entry in ledger.entries().iter().rev() {
+            table.add_row(vec![
+                entry.version.clone(),
+                entry.channel.clone().unwrap_or_else(|| String::from("-")),
+                entry.timestamp.clone(),
+                entry.commit.as_deref().map(short_hash).unwrap_or("-").to_owned(),
+                short_hash(&entry.hash).to_owned(),
+                match entry.asset {
+                    Some(Asset { id, version: Some(version) }) => format!("{} v{}", id, version),
+                    Some(Asset { id, version: None }) => id.to_string(),
+                    None => String::from("-"),
+                },
+                entry.notes.as_deref().and_then(|notes| notes.lines().next()).unwrap_or("").to_owned(),
+            ]);
+        }
+        
+        print!("{}", table);
+        
+        Ok(())
+    }
+    
+    fn show_diff(&self, project_path: &Path, version: &str) -> Result<()> {
+        let ledger = Ledger::load(project_path.get_parent())?;
+        
+        let Some(entry) = ledger.find(version) else {
+            bail!("Version {} was never published, see {}", version, ledger::LEDGER_FILE);
+        };
+        
+        let artifact = Artifact::build(project_path)?;
+        let changes = entry.diff(&artifact.files);
+        
+        if changes.is_empty() {
+            if artifact.hash == entry.hash {
+                println!("{}", format!("No changes since {}", entry.version).green());
+            } else {
+                println!("{}", format!("No file changes since {}, but the built plugin differs", entry.version).yellow());
+            }
+            
+            return Ok(());
+        }
+        
+        println!("{}", format!("{} files changed since {}:", changes.len(), entry.version).blue().bold());
+        
+        for (path, change) in changes {
+            println!("  {} {}", change.marker(), path);
+        }
+        
+        Ok(())
+    }
+    
+    /// Write the payload to disk and compare it with the previous one
+    fn dry_run(&self, project_path: &Path, metadata: &PluginMetadata) -> Result<()> {
+        let workspace_dir = project_path.get_parent();
+        
+        println!("{}", "Step 5: Building payload...".cyan());
+        
+        let artifact = Artifact::build(project_path)?;
+        let mut payload = Payload::new(&artifact, metadata, self.notes.clone());
+        payload.channel = self.channel.clone();
+        
+        // Load the baseline first, `--against` may point to the directory we are about to overwrite
+        let baseline = match &self.against {
+            Some(path) => Some((path.to_string(), Payload::load(path)?)),
+            None => {
+                let ledger = Ledger::load(workspace_dir)?;
+                
+                let last = match &self.channel {
+                    Some(channel) => ledger.last_in(channel),
+                    None => ledger.last(),
+                };
+                
+                last.map(|entry| (format!("published {}", entry.version), Payload::from(entry)))
+            }
+        };
+        
+        let output = self.output.clone().unwrap_or_else(|| workspace_dir.join(payload::DRY_RUN_DIR));
+        let path = payload.save(&output, &artifact.data)?;
+        
+        write_icons(workspace_dir, metadata, &output.join("icons"))?;
+        
+        println!("\n{}", "📦 Payload:".blue().bold());
+        println!("{}: {}", "Hash".cyan().bold(), payload.hash);
+        println!("{}: {} bytes", "Size".cyan().bold(), payload.size);
+        println!("{}: {}", "Files".cyan().bold(), payload.files.len());
+        
+        for (path, file) in &payload.files {
+            println!("  {} {} {} {}", "•".blue(), path, format!("{} bytes", file.size).dimmed(), short_hash(&file.hash).dimmed());
+        }
+        
+        match baseline {
+            Some((label, previous)) => Self::show_payload_diff(&label, &previous, &payload),
+            None => println!("\n{}", "Nothing to compare with, this plugin has not been published yet".yellow()),
+        }
+        
+        println!("\n{} {}", "✅ Dry run written to".green().bold(), path.to_string().bold());
+        println!("{}", "Nothing was sent to Studio or Open Cloud.".green());
+        
+        Ok(())
+    }
+    
+    fn show_payload_diff(label: &str, previous: &Payload, payload: &Payload) {
+        let metadata = payload::diff_metadata(&previous.metadata, &payload.metadata);
+        let files = ledger::diff_files(&previous.hashes(), &payload.hashes());
+        
+        let notes = previous.notes != payload.notes;
+        
+        if metadata.is_empty() && files.is_empty() && !notes {
+            if payload.hash == previous.hash {
+                println!("\n{}", format!("No changes since {}", label).green());
+            } else {
+                println!("\n{}", format!("No metadata or file changes since {}, but the built plugin differs", label).yellow());
+            }
+            
+            return;
+        }
+        
+        println!("\n{}", format!("Changes since {}:", label).blue().bold());
+        
+        let show = |value: &Option<serde_json::Value>| value.as_ref().map(|value| value.to_string()).unwrap_or_else(|| String::from("none"));
+        
+        for change in metadata {
+            println!("  {} {}: {} → {}", FileChange::Modified.marker(), change.field.bold(), show(&change.old), show(&change.new));
+        }
+        
+        if notes {
+            println!("  {} {}: {}", FileChange::Modified.marker(), "notes".bold(), payload.notes.as_deref().unwrap_or("none"));
+        }
+        
+        for (path, change) in files {
+            println!("  {} {}", change.marker(), path);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, ValueEnum)]
+pub(super) enum Target {
+    /// Studio plugin connected to `fluxo serve`
+    #[default]
+    Studio,
+    /// Roblox Open Cloud assets API, does not need Studio
+    Cloud,
+}
+
+/// Contents of files written back by a release, `None` if a file did not exist
+struct Rollback(Vec<(PathBuf, Option<Vec<u8>>)>);
+
+impl Rollback {
+    fn capture(paths: &[PathBuf]) -> Result<Self> {
+        let mut files = Vec::new();
+        
+        for path in paths {
+            let contents = if path.exists() {
+                Some(fs::read(path).with_desc(|| format!("Failed to read {}", path.to_string()))?)
+            } else {
+                None
+            };
+            
+            files.push((path.clone(), contents));
+        }
+        
+        Ok(Self(files))
+    }
+    
+    fn restore(self) -> Result<()> {
+        for (path, contents) in self.0 {
+            match contents {
+                Some(contents) => fs::write(&path, contents),
+                None => fs::remove_file(&path),
+            }
+            .with_desc(|| format!("Failed to restore {}", path.to_string()))?;
+        }
+        
+        Ok(())
+    }
+}
+
+/// Write resized variants of the local plugin icon to `dir`, if there is one
+fn write_icons(workspace_dir: &Path, metadata: &PluginMetadata, dir: &Path) -> Result<()> {
+    let Some(path) = icon::locate(workspace_dir, Some(metadata)) else {
+        return Ok(());
+    };
+    
+    let icon = Icon::load(&workspace_dir.join(&path))?;
+    icon.write_variants(dir)?;
+    
+    println!("{} {}", "🖼️  Icon variants written to".green(), dir.to_string());
+    
+    Ok(())
+}
+
+/// Returns the first 7 characters of a commit or content hash
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(7)]
+}
+
+pub(super) fn check_studio_connection(address: &str) -> Result<bool> {
+    println!("{}", "🔍 Connecting to Studio...".cyan());
+    
+    let url = format!("{}/health", address);
+    
+    match reqwest::blocking::get(&url) {
+        Ok(response) => {
+            if response.status().is_success() {
+                println!("{}", "✅ Studio plugin connection verified".green());
+                Ok(true)
+            } else {
+                println!("{}", "❌ Studio plugin not responding".red());
+                Ok(false)
+            }
+        },
+        Err(_) => {
+            println!("{}", "❌ Cannot reach Studio plugin".red());
+            Ok(false)
+        }
+    }
+}
+
+/// Returns whether Studio accepted the request
+pub(super) fn send_publish_request(address: &str, payload: &Payload) -> Result<bool> {
+    println!("{}", "📤 Sending publish request to Studio...".cyan());
+    
+    let publish_data = payload.request();
+    
+    let client = reqwest::blocking::Client::new();
+    let url = format!("{}/publish", address);
+    
+    let mut request = client.post(&url).json(&publish_data);
+    
+    if let Some(token) = sessions::get_token(address)? {
+        request = request.header(TOKEN_HEADER, token);
+    }
+    
+    match request.send() {
+        Ok(response) => {
+            if response.status().is_success() {
+                if let Ok(result) = response.json::<serde_json::Value>() {
+                    if result["success"].as_bool().unwrap_or(false) {
                         println!("{}", "✅ Publish request sent successfully!".green());
+                        println!("{}", "🎯 Studio will show the publish confirmation dialog".cyan());
+                    } else {
+                        let error = result["error"].as_str().unwrap_or("Unknown error");
+                        println!("{} Publish failed: {}", "❌".red(), error);
+                        return Ok(false);
                     }
                 } else {
-                    println!("{} Studio publish failed: {}", "❌".red(), response.status());
+                    println!("{}", "✅ Publish request sent successfully!".green());
                 }
-            },
-            Err(e) => {
-                println!("{} Failed to send publish request: {}", "❌".red(), e);
-                println!("💡 Make sure 'fluxo serve' is running and Studio is open");
+                
+                Ok(true)
+            } else {
+                println!("{} Studio publish failed: {}", "❌".red(), response.status());
+                Ok(false)
             }
+        },
+        Err(e) => {
+            println!("{} Failed to send publish request: {}", "❌".red(), e);
+            println!("💡 Make sure 'fluxo serve' is running and Studio is open");
+            Ok(false)
         }
+    }
+}
+
+/// Print the published asset and a hint to configure it if it was just created
+pub(super) fn print_asset(asset: Asset, asset_id: Option<u64>, channel: Option<&str>) {
+    match asset.version {
+        Some(version) => println!("{} {} {} {}", "✅ Published asset".green().bold(), asset.id, "version".green().bold(), version),
+        None => println!("{} {}", "✅ Published asset".green().bold(), asset.id),
+    }
+    
+    if asset_id.is_none() {
+        let key = match channel {
+            Some(channel) => format!("publishing.channels.{}.assetId", channel),
+            None => String::from("publishing.assetId"),
+        };
         
-        Ok(())
+        println!("💡 Set {} to {} in fluxo.config.json to update this asset next time", key, asset.id);
     }
 }
@@ -3,7 +3,6 @@ use clap::Parser;
 use colored::Colorize;
 use log::{debug, info};
 use std::{path::PathBuf, process, sync::Arc, thread};
-use tokio;
 
 use crate::{
 	argon_error, argon_info, argon_warn,
@@ -13,7 +12,7 @@ use crate::{
 	integration,
 	program::{Program, ProgramName},
 	project::{self, Project},
-	server::{self, Server, http::HttpServer},
+	server::{self, Server},
 	sessions,
 };
 
@@ -44,6 +43,10 @@ pub struct Serve {
 	#[arg(short, long)]
 	ts: bool,
 
+	/// Validate the project on every change and push diagnostics to clients
+	#[arg(long)]
+	validate: bool,
+
 	/// Run Argon asynchronously
 	#[arg(short = 'A', long = "async")]
 	run_async: bool,
@@ -134,6 +137,12 @@ impl Serve {
 
 		let core = Arc::new(core);
 
+		if self.validate {
+			core.processor().enable_validation();
+
+			argon_info!("Live validation enabled");
+		}
+
 		if let Some(path) = sourcemap_path {
 			let core = core.clone();
 			let queue = core.queue();
@@ -157,45 +166,25 @@ impl Serve {
 			});
 		}
 
+		let server = Server::new(core, &host, port);
+
 		sessions::add(
 			self.session,
 			Some(host.clone()),
 			Some(port),
+			Some(server.token().to_owned()),
 			process::id(),
 			config.run_async,
 		)?;
 
-		let server = Server::new(core, &host, port);
-
 		argon_info!(
 			"Serving on: {}, project: {}",
 			server::format_address(&host, port).bold(),
 			project_path.to_string().bold()
 		);
 
-		// Start Fluxo HTTP server on a different port for Studio communication
-		let fluxo_port = port + 1000; // e.g., if Argon is on 8080, Fluxo is on 9080
-		let project_for_server = Project::load(&project_path)?;
-		
-		argon_info!(
-			"Starting Fluxo HTTP server on: {}",
-			server::format_address(&host, fluxo_port).bold()
-		);
-		
-		// Start Fluxo HTTP server in a separate thread
-		let fluxo_host = host.clone();
-		thread::spawn(move || {
-			let rt = tokio::runtime::Runtime::new().unwrap();
-			rt.block_on(async {
-				let http_server = HttpServer::new(fluxo_port);
-				http_server.set_project(project_for_server).await;
-				if let Err(e) = http_server.start().await {
-					argon_error!("Fluxo HTTP server error: {}", e);
-				}
-			});
-		});
+		argon_info!("Session token stored in: {}", sessions::get_path()?.to_string().bold());
 
-		// Start the original Argon server
 		server.start()?;
 
 		Ok(())
@@ -230,6 +219,10 @@ impl Serve {
 			args.push("--ts".into());
 		}
 
+		if self.validate {
+			args.push("--validate".into());
+		}
+
 		Program::new(ProgramName::Argon).args(args).spawn()?;
 
 		Ok(())
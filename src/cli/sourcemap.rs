@@ -90,7 +90,7 @@ impl Sourcemap {
 		}
 
 		if self.watch {
-			sessions::add(self.session, None, None, process::id(), config.run_async)?;
+			sessions::add(self.session, None, None, None, process::id(), config.run_async)?;
 
 			if self.output.is_some() {
 				argon_info!("Watching for changes..");
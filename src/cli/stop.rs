@@ -3,7 +3,7 @@ use clap::Parser;
 use colored::Colorize;
 use reqwest::blocking::Client;
 
-use crate::{argon_info, argon_warn, logger::Table, sessions, util};
+use crate::{argon_info, argon_warn, logger::Table, server::TOKEN_HEADER, sessions, util};
 
 /// Stop Argon session by address, ID or all running sessions
 #[derive(Parser)]
@@ -66,7 +66,7 @@ impl Stop {
 
 			for (_, session) in sessions {
 				if let Some(address) = session.get_address() {
-					Self::make_request(&address, session.pid);
+					Self::make_request(&address, session.token.as_deref(), session.pid);
 				} else {
 					Self::kill_process(session.pid);
 				}
@@ -78,7 +78,7 @@ impl Stop {
 		if self.session.is_empty() {
 			if let Some(session) = sessions::get(None, self.host, self.port)? {
 				if let Some(address) = session.get_address() {
-					Self::make_request(&address, session.pid);
+					Self::make_request(&address, session.token.as_deref(), session.pid);
 				} else {
 					Self::kill_process(session.pid);
 				}
@@ -95,7 +95,7 @@ impl Stop {
 			} else {
 				for session in sessions.values() {
 					if let Some(address) = session.get_address() {
-						Self::make_request(&address, session.pid);
+						Self::make_request(&address, session.token.as_deref(), session.pid);
 					} else {
 						Self::kill_process(session.pid);
 					}
@@ -108,10 +108,16 @@ impl Stop {
 		Ok(())
 	}
 
-	fn make_request(address: &String, pid: u32) {
+	fn make_request(address: &String, token: Option<&str>, pid: u32) {
 		let url = format!("{}/stop", address);
 
-		match Client::new().post(url).send() {
+		let mut request = Client::new().post(url);
+
+		if let Some(token) = token {
+			request = request.header(TOKEN_HEADER, token);
+		}
+
+		match request.send().and_then(|response| response.error_for_status()) {
 			Ok(_) => argon_info!("Stopped Argon session with address: {}", address.bold()),
 			Err(_) => {
 				Self::kill_process(pid);
@@ -1,15 +1,19 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
-use std::path::PathBuf;
+use std::{path::PathBuf, thread};
 use colored::Colorize;
-use serde_json;
-use walkdir;
+use serde::Serialize;
 
 use crate::{
     config::Config,
+    constants::SYNC_RETRY_TIME,
+    core::Core,
     ext::PathExt,
-    project,
-    server,
+    project::{self, Project},
+    sessions,
+    server::{Message, SyncChanges, TOKEN_HEADER},
+    sync::{ChangesRequest, Manifest, SyncRequest, SyncResponse},
+    validation::{format, validator::Validator},
 };
 
 /// Sync local plugin code to Studio
@@ -23,9 +27,13 @@ pub struct Sync {
     #[arg(short, long)]
     watch: bool,
     
-    /// Port to use for communication with Studio
-    #[arg(short, long, default_value = "8080")]
-    port: u16,
+    /// Server host name, defaults to the last `fluxo serve` session
+    #[arg(short = 'H', long)]
+    host: Option<String>,
+    
+    /// Server port, defaults to the last `fluxo serve` session
+    #[arg(short, long)]
+    port: Option<u16>,
 }
 
 impl Sync {
@@ -38,21 +46,18 @@ impl Sync {
         
         // First validate the project
         println!("{}", "Validating project before sync...".cyan());
-        let validate_cmd = super::validate::Validate {
-            project: self.project.clone(),
-            skip_metadata: false,
-            skip_api_check: false,
-            skip_structure: false,
-            verbose: false,
-        };
+        let report = Validator::new().run(project_path.get_parent());
+        print!("{}", format::to_text(&report, false));
         
-        if let Err(e) = validate_cmd.main() {
+        if !report.success {
             println!("{}", "❌ Validation failed. Fix errors before syncing.".red());
-            return Err(e);
+            bail!("Validation failed with {} errors", report.summary.errors);
         }
         
+        let address = sessions::get_address(self.host.clone(), self.port)?;
+        
         // Check if Studio is running and has the Fluxo plugin
-        if !self.check_studio_connection()? {
+        if !check_studio_connection(&address)? {
             println!("{}", "❌ Could not connect to Studio. Make sure:".red());
             println!("  {} Roblox Studio is running", "•".red());
             println!("  {} Fluxo Studio plugin is installed and enabled", "•".red());
@@ -61,150 +66,173 @@ impl Sync {
         
         println!("{}", "✅ Connected to Studio".green());
         
+        // Watch mode keeps the tree up to date with the file system
+        let core = Core::new(Project::load(&project_path)?, self.watch)?;
+        
         // Perform initial sync
-        self.sync_files(&project_path)?;
+        let Some(hash) = sync_all(&address, &core)? else {
+            bail!("Initial sync failed");
+        };
         
         if self.watch {
             println!("{}", "👁️  Watching for changes... (Press Ctrl+C to stop)".yellow());
-            self.start_watcher(&project_path)?;
+            watch_changes(&address, &core, hash)?;
         } else {
             println!("{}", "✅ Sync completed!".green().bold());
         }
         
         Ok(())
     }
+}
+
+fn check_studio_connection(address: &str) -> Result<bool> {
+    // Try to connect to Studio plugin via HTTP
+    println!("{}", "🔍 Checking Studio connection...".cyan());
     
-    fn check_studio_connection(&self) -> Result<bool> {
-        // Try to connect to Studio plugin via HTTP
-        println!("{}", "🔍 Checking Studio connection...".cyan());
-        
-        let url = format!("http://localhost:{}/health", self.port + 1000); // Fluxo HTTP port
-        
-        // Use blocking reqwest for simplicity in CLI
-        match reqwest::blocking::get(&url) {
-            Ok(response) => {
-                if response.status().is_success() {
-                    println!("{}", "✅ Studio plugin connection verified".green());
-                    Ok(true)
-                } else {
-                    println!("{}", "❌ Studio plugin not responding".red());
-                    Ok(false)
-                }
-            },
-            Err(_) => {
-                println!("{}", "❌ Cannot reach Studio plugin".red());
+    let url = format!("{}/health", address);
+    
+    // Use blocking reqwest for simplicity in CLI
+    match reqwest::blocking::get(&url) {
+        Ok(response) => {
+            if response.status().is_success() {
+                println!("{}", "✅ Studio plugin connection verified".green());
+                Ok(true)
+            } else {
+                println!("{}", "❌ Studio plugin not responding".red());
                 Ok(false)
             }
+        },
+        Err(_) => {
+            println!("{}", "❌ Cannot reach Studio plugin".red());
+            Ok(false)
         }
     }
+}
+
+/// Send the whole tree, returns the content hash Studio has afterwards
+fn sync_all(address: &str, core: &Core) -> Result<Option<String>> {
+    println!("{}", "📁 Building project snapshot...".cyan());
     
-    fn sync_files(&self, project_path: &std::path::Path) -> Result<()> {
-        println!("{}", "📁 Syncing files...".cyan());
-        
-        // Read project files
-        let src_dir = project_path.join("src");
-        let mut files = std::collections::HashMap::new();
-        
-        if src_dir.exists() {
-            for entry in walkdir::WalkDir::new(&src_dir) {
-                let entry = entry?;
-                if entry.file_type().is_file() {
-                    let relative_path = entry.path().strip_prefix(&src_dir)?;
-                    let content = std::fs::read_to_string(entry.path())?;
-                    files.insert(relative_path.to_string_lossy().to_string(), content);
-                }
-            }
+    let request = SyncRequest::new(core)?;
+    
+    println!("{}", "📡 Sending sync request to Studio...".cyan());
+    
+    match send(address, "sync", &request) {
+        Some(response) if response.success => {
+            println!("{} Sync completed successfully!", "✅".green());
+            println!(
+                "  Synced {} instances, content hash {}",
+                request.instances.to_string().bold(),
+                request.hash.bold()
+            );
+            
+            Ok(Some(request.hash))
         }
-        
-        // Read metadata if it exists
-        let meta_file = project_path.join("plugin.meta.json");
-        let metadata = if meta_file.exists() {
-            let meta_content = std::fs::read_to_string(meta_file)?;
-            serde_json::from_str(&meta_content)?
-        } else {
-            serde_json::json!({})
-        };
-        
-        // Prepare sync data
-        let sync_data = serde_json::json!({
-            "files": files,
-            "metadata": metadata,
-            "projectPath": project_path.to_string_lossy()
-        });
-        
-        // Send to Studio plugin
-        let client = reqwest::blocking::Client::new();
-        let url = format!("http://localhost:{}/sync", self.port + 1000);
-        
-        println!("{}", "📡 Sending sync request to Studio...".cyan());
-        
-        match client.post(&url)
-            .json(&sync_data)
-            .send() 
-        {
-            Ok(response) => {
-                if response.status().is_success() {
-                    if let Ok(result) = response.json::<serde_json::Value>() {
-                        if result["success"].as_bool().unwrap_or(false) {
-                            println!("{} Sync completed successfully!", "✅".green());
-                            
-                            // Log file details
-                            if !files.is_empty() {
-                                println!("  Synced files:");
-                                for filename in files.keys() {
-                                    println!("    {} {}", "📄".blue(), filename);
-                                }
-                            }
-                        } else {
-                            let error = result["error"].as_str().unwrap_or("Unknown error");
-                            println!("{} Sync failed: {}", "❌".red(), error);
-                        }
-                    } else {
-                        println!("{} Sync request sent successfully!", "✅".green());
-                    }
-                } else {
-                    println!("{} Studio sync failed: {}", "❌".red(), response.status());
-                }
-            },
-            Err(e) => {
-                println!("{} Failed to send sync request: {}", "❌".red(), e);
-                println!("💡 Make sure 'fluxo serve' is running and Studio is open");
-            }
+        Some(response) => {
+            let error = response.error.unwrap_or_else(|| "Unknown error".into());
+            println!("{} Sync failed: {}", "❌".red(), error);
+            
+            Ok(None)
         }
-        
-        Ok(())
+        None => Ok(None),
     }
+}
+
+/// Send only the changes found by the processor, the whole
+/// tree is sent again only when Studio asks for it
+fn watch_changes(address: &str, core: &Core, mut hash: String) -> Result<()> {
+    let queue = core.queue();
+    queue.subscribe_internal()?;
     
-    fn start_watcher(&self, project_path: &std::path::Path) -> Result<()> {
-        use notify::{Watcher, RecursiveMode, watcher};
-        use std::sync::mpsc::channel;
-        use std::time::Duration;
+    let mut manifest = Manifest::new(core);
+    
+    loop {
+        let Message::SyncChanges(SyncChanges(mut changes)) = queue.get_change(0)? else {
+            continue;
+        };
         
-        let (tx, rx) = channel();
-        let mut watcher = watcher(tx, Duration::from_secs(1))?;
+        let files = manifest.update(core, &mut changes);
         
-        watcher.watch(&project_path.join("src"), RecursiveMode::Recursive)?;
+        for (path, change) in &files {
+            println!("{} {}", change.marker(), path);
+        }
         
-        let meta_file = project_path.join("plugin.meta.json");
-        if meta_file.exists() {
-            watcher.watch(&meta_file, RecursiveMode::NonRecursive)?;
+        if changes.is_empty() {
+            continue;
         }
         
-        loop {
-            match rx.recv() {
-                Ok(event) => {
-                    println!("{} File changed: {:?}", "🔄".yellow(), event);
-                    if let Err(e) = self.sync_files(project_path) {
-                        println!("{} Sync error: {}", "❌".red(), e);
-                    }
-                },
-                Err(e) => {
-                    println!("{} Watch error: {}", "❌".red(), e);
-                    break;
+        let request = ChangesRequest::new(core, hash.clone(), changes)?;
+        
+        // Studio still has `hash` after a failed request, so the same changes apply
+        hash = loop {
+            match send(address, "sync/changes", &request) {
+                Some(response) if response.resync => {
+                    println!("{}", "🔄 Studio requested a full resync".yellow());
+                    break resync(address, core)?;
+                }
+                Some(response) if response.success => {
+                    println!(
+                        "{} Synced {} additions, {} updates and {} removals",
+                        "✅".green(),
+                        request.changes.additions.len().to_string().green(),
+                        request.changes.updates.len().to_string().blue(),
+                        request.changes.removals.len().to_string().red()
+                    );
+                    
+                    break request.hash.clone();
+                }
+                Some(response) => {
+                    let error = response.error.unwrap_or_else(|| "Unknown error".into());
+                    println!("{} Sync failed: {}", "❌".red(), error);
                 }
+                None => {}
             }
+            
+            println!("{}", "🔁 Retrying...".yellow());
+            thread::sleep(SYNC_RETRY_TIME);
+        };
+    }
+}
+
+/// Send the whole tree until Studio accepts it
+fn resync(address: &str, core: &Core) -> Result<String> {
+    loop {
+        if let Some(hash) = sync_all(address, core)? {
+            return Ok(hash);
         }
         
-        Ok(())
+        println!("{}", "🔁 Retrying...".yellow());
+        thread::sleep(SYNC_RETRY_TIME);
+    }
+}
+
+/// Post `body` to the server, `None` if it could not be reached
+fn send(address: &str, endpoint: &str, body: &impl Serialize) -> Option<SyncResponse> {
+    let client = reqwest::blocking::Client::new();
+    let url = format!("{}/{}", address, endpoint);
+    
+    let mut request = client.post(&url).json(body);
+    
+    if let Ok(Some(token)) = sessions::get_token(address) {
+        request = request.header(TOKEN_HEADER, token);
+    }
+    
+    match request.send() {
+        Ok(response) => {
+            if response.status().is_success() {
+                Some(response.json::<SyncResponse>().unwrap_or(SyncResponse {
+                    success: true,
+                    ..Default::default()
+                }))
+            } else {
+                println!("{} Studio sync failed: {}", "❌".red(), response.status());
+                None
+            }
+        },
+        Err(e) => {
+            println!("{} Failed to send sync request: {}", "❌".red(), e);
+            println!("💡 Make sure 'fluxo serve' is running and Studio is open");
+            None
+        }
     }
 }
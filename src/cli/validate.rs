@@ -1,13 +1,26 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
-use std::path::PathBuf;
 use colored::Colorize;
-use walkdir;
+use log::LevelFilter;
+use std::{fs, path::PathBuf};
 
 use crate::{
     config::Config,
     ext::PathExt,
-    project,
+    plugin::{config::PluginConfig, metadata::PluginMetadata, team::Permission, META_FILE},
+    project, util,
+    validation::{
+        checks::{
+            budgets::{self, BudgetsCheck},
+            metadata,
+            properties::PropertiesCheck,
+            requires::RequiresCheck,
+            sources, structure,
+        },
+        format::{self, OutputFormat},
+        validator::Validator,
+        Severity,
+    },
 };
 
 /// Validate plugin code and metadata before publishing
@@ -21,7 +34,7 @@ pub struct Validate {
     #[arg(long)]
     skip_metadata: bool,
     
-    /// Skip banned API check
+    /// Skip banned API and custom rule checks
     #[arg(long)]
     skip_api_check: bool,
     
@@ -29,157 +42,170 @@ pub struct Validate {
     #[arg(long)]
     skip_structure: bool,
     
-    /// Show verbose validation output
+    /// Output format: text, json, sarif, junit
+    #[arg(short, long, default_value = "text", hide_possible_values = true)]
+    format: OutputFormat,
+    
+    /// Write the report to a file instead of stdout
     #[arg(short, long)]
-    verbose: bool,
+    output: Option<PathBuf>,
+    
+    /// Fix problems in plugin metadata that can be fixed automatically
+    #[arg(long)]
+    fix: bool,
+    
+    /// Act as this team member when fixing, requires the FLUXO_TEAM_TOKEN environment variable
+    #[arg(long = "as", value_name = "MEMBER", requires = "fix")]
+    as_member: Option<String>,
+    
+    /// Check for unresolved and cyclic requires in the built project
+    #[arg(long)]
+    requires: bool,
+    
+    /// Write the require graph to a DOT or Mermaid (.mmd) file, implies --requires
+    #[arg(long, value_name = "PATH")]
+    graph: Option<PathBuf>,
+    
+    /// Check classes and properties in project, .meta.json and .model.json files
+    #[arg(long)]
+    properties: bool,
+    
+    /// Check size and instance budgets, even if not configured in fluxo.config.json
+    #[arg(long)]
+    budgets: bool,
+    
+    /// Check every file again instead of reusing results from .fluxo/cache
+    #[arg(long)]
+    no_cache: bool,
+    
+    /// Lowest severity that fails validation: error, warning, info
+    #[arg(long, value_name = "SEVERITY", default_value = "error", hide_possible_values = true)]
+    fail_on: Severity,
 }
 
 impl Validate {
     pub fn main(self) -> Result<()> {
-        println!("{}", "🔍 Validating plugin...".blue().bold());
+        let is_text = self.format == OutputFormat::Text;
         
-        let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
-        
-        Config::load_workspace(project_path.get_parent());
+        // With `--output` the whole text report goes to the file, without color codes
+        let mut text = String::new();
+        let to_file = is_text && self.output.is_some();
         
-        let mut errors = Vec::new();
-        let mut warnings = Vec::new();
+        if to_file {
+            colored::control::set_override(false);
+        }
         
-        // 1. Validate project structure
-        if !self.skip_structure {
-            if let Err(e) = self.validate_structure(&project_path) {
-                errors.push(format!("Structure validation failed: {}", e));
+        let mut emit = |line: String| {
+            if to_file {
+                text.push_str(&line);
+                text.push('\n');
             } else {
-                println!("{}", "✅ Project structure is valid".green());
+                println!("{}", line);
             }
-        }
+        };
         
-        // 2. Validate metadata
-        if !self.skip_metadata {
-            match self.validate_metadata(&project_path) {
-                Ok(metadata_warnings) => {
-                    warnings.extend(metadata_warnings);
-                    println!("{}", "✅ Metadata validation passed".green());
-                },
-                Err(e) => {
-                    errors.push(format!("Metadata validation failed: {}", e));
-                }
-            }
+        if is_text {
+            emit("🔍 Validating plugin...".blue().bold().to_string());
         }
         
-        // 3. Check for banned APIs
-        if !self.skip_api_check {
-            match self.check_banned_apis(&project_path) {
-                Ok(api_warnings) => {
-                    warnings.extend(api_warnings);
-                    if api_warnings.is_empty() {
-                        println!("{}", "✅ No banned APIs detected".green());
-                    }
-                },
-                Err(e) => {
-                    errors.push(format!("API validation failed: {}", e));
+        let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
+        let workspace_dir = project_path.get_parent();
+        
+        Config::load_workspace(workspace_dir);
+        
+        if self.fix && !self.skip_metadata && workspace_dir.join(META_FILE).exists() {
+            let mut metadata = PluginMetadata::load(workspace_dir)?;
+            let fixes = metadata.fix();
+            
+            if !fixes.is_empty() {
+                PluginConfig::load(workspace_dir)?.team.check(
+                    workspace_dir,
+                    self.as_member.as_deref(),
+                    Permission::EditMetadata,
+                )?;
+                
+                metadata.save(workspace_dir)?;
+            }
+            
+            if is_text && !fixes.is_empty() {
+                emit("🔧 Applied metadata fixes:".blue().to_string());
+                
+                for fix in fixes {
+                    emit(format!("  {} {}", "•".blue(), fix));
                 }
             }
         }
         
-        // Report results
-        if !warnings.is_empty() {
-            println!("\n{}", "⚠️  Warnings:".yellow().bold());
-            for warning in &warnings {
-                println!("  {} {}", "•".yellow(), warning);
-            }
-        }
+        let mut validator = Validator::new();
         
-        if !errors.is_empty() {
-            println!("\n{}", "❌ Errors:".red().bold());
-            for error in &errors {
-                println!("  {} {}", "•".red(), error);
+        for (skip, name) in [
+            (self.skip_structure, structure::RULE),
+            (self.skip_metadata, metadata::RULE),
+            (self.skip_api_check, sources::RULE),
+        ] {
+            if skip {
+                validator = validator.without(name);
             }
-            return Err(anyhow::anyhow!("Validation failed with {} errors", errors.len()));
         }
         
-        println!("\n{}", "✅ Plugin validation completed successfully!".green().bold());
-        Ok(())
-    }
-    
-    fn validate_structure(&self, project_path: &std::path::Path) -> Result<()> {
-        // Check for required files and folders
-        let src_dir = project_path.join("src");
-        if !src_dir.exists() {
-            return Err(anyhow::anyhow!("Missing 'src' directory"));
+        if self.requires || self.graph.is_some() {
+            let mut check = RequiresCheck::new();
+            
+            if let Some(graph) = self.graph {
+                check = check.with_graph(graph);
+            }
+            
+            validator = validator.with_check(check);
         }
         
-        let meta_file = project_path.join("plugin.meta.json");
-        if !meta_file.exists() {
-            return Err(anyhow::anyhow!("Missing 'plugin.meta.json' file"));
+        if self.no_cache {
+            validator = validator.without_cache();
         }
         
-        let config_file = project_path.join("fluxo.config.json");
-        if !config_file.exists() {
-            return Err(anyhow::anyhow!("Missing 'fluxo.config.json' file"));
+        if self.budgets {
+            validator = validator.without(budgets::RULE).with_check(BudgetsCheck::always());
         }
         
-        Ok(())
-    }
-    
-    fn validate_metadata(&self, project_path: &std::path::Path) -> Result<Vec<String>> {
-        let mut warnings = Vec::new();
-        
-        let meta_file = project_path.join("plugin.meta.json");
-        let metadata: serde_json::Value = serde_json::from_str(
-            &std::fs::read_to_string(meta_file)?
-        )?;
-        
-        // Check required fields
-        if metadata.get("name").is_none() || metadata["name"].as_str().unwrap_or("").is_empty() {
-            return Err(anyhow::anyhow!("Plugin name is required"));
+        if self.properties {
+            validator = validator.with_check(PropertiesCheck);
         }
         
-        if metadata.get("description").is_none() || metadata["description"].as_str().unwrap_or("").is_empty() {
-            warnings.push("Plugin description is empty".to_string());
-        }
+        let report = validator.run(workspace_dir);
         
-        if metadata.get("version").is_none() {
-            return Err(anyhow::anyhow!("Plugin version is required"));
-        }
+        let failures = report.count_at_least(self.fail_on);
         
-        // Validate version format (semver)
-        if let Some(version) = metadata["version"].as_str() {
-            if !version.contains('.') {
-                warnings.push("Version should follow semantic versioning (e.g., 1.0.0)".to_string());
+        if is_text {
+            // Informational diagnostics are shown only with `--verbose`
+            let verbose = util::env_verbosity() > LevelFilter::Error;
+            
+            let body = format::to_text(&report, verbose);
+            
+            if !body.is_empty() {
+                emit(body.trim_end_matches('\n').to_owned());
+            }
+            
+            if failures == 0 {
+                emit(format!("\n{}", "✅ Plugin validation completed successfully!".green().bold()));
+            }
+            
+            if let Some(output) = &self.output {
+                colored::control::unset_override();
+                fs::write(output, text)?;
+            }
+        } else {
+            let rendered = format::render(&report, self.format, self.fail_on)?;
+            
+            if let Some(output) = &self.output {
+                fs::write(output, rendered)?;
+            } else {
+                println!("{}", rendered);
             }
         }
         
-        Ok(warnings)
-    }
-    
-    fn check_banned_apis(&self, project_path: &std::path::Path) -> Result<Vec<String>> {
-        let mut warnings = Vec::new();
-        let banned_apis = vec!["loadstring", "getfenv", "setfenv", "debug.getupvalue"];
-        
-        let src_dir = project_path.join("src");
-        
-        for entry in walkdir::WalkDir::new(src_dir) {
-            let entry = entry?;
-            if entry.file_type().is_file() {
-                if let Some(ext) = entry.path().extension() {
-                    if ext == "lua" || ext == "luau" {
-                        let content = std::fs::read_to_string(entry.path())?;
-                        
-                        for api in &banned_apis {
-                            if content.contains(api) {
-                                warnings.push(format!(
-                                    "Potentially banned API '{}' found in {}",
-                                    api,
-                                    entry.path().display()
-                                ));
-                            }
-                        }
-                    }
-                }
-            }
+        if failures > 0 {
+            bail!("Validation failed with {} {}s or worse", failures, self.fail_on);
         }
         
-        Ok(warnings)
+        Ok(())
     }
 }
@@ -27,6 +27,16 @@ pub const QUEUE_TIMEOUT: Duration = Duration::from_secs(60);
 // this saves a lot of computing time
 pub const SYNCBACK_DEBOUNCE_TIME: Duration = Duration::from_millis(200);
 
+// Live validation waits until no VFS events arrived for
+// this amount of time, so saving many files at once
+// validates the project only once
+pub const VALIDATION_DEBOUNCE_TIME: Duration = Duration::from_millis(300);
+
+// How long `fluxo sync --watch` waits before sending
+// a request that failed again, the server might have
+// been restarted or the connection was interrupted
+pub const SYNC_RETRY_TIME: Duration = Duration::from_secs(1);
+
 // Set of default sync rules that is used to determine
 // what middleware should be used to process a file
 // users can override these rules in the project file
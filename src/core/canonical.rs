@@ -0,0 +1,94 @@
+use rbx_dom_weak::{
+	types::{Ref, Variant},
+	InstanceBuilder, WeakDom,
+};
+use std::{collections::HashMap, str::FromStr};
+
+use crate::util;
+
+/// Returns a copy of `roots` and all of their descendants under a new root, with
+/// children sorted by name and class and referents derived from instance paths.
+///
+/// Serializers already write properties sorted by name, so two builds of the same
+/// sources always produce the same bytes, regardless of the file system order
+pub fn canonicalize(dom: &WeakDom, roots: &[Ref]) -> (WeakDom, Vec<Ref>) {
+	let root = dom.root();
+
+	let mut canonical = WeakDom::new(
+		InstanceBuilder::new(root.class)
+			.with_name(&root.name)
+			.with_referent(stable_ref("")),
+	);
+
+	let mut refs = HashMap::new();
+	let mut new_roots = Vec::new();
+
+	for (id, path) in sorted(dom, roots, "") {
+		let builder = build(dom, id, &path, &mut refs);
+		new_roots.push(canonical.insert(canonical.root_ref(), builder));
+	}
+
+	// Point all references to the copied instances
+	let ids: Vec<Ref> = canonical.descendants().map(|instance| instance.referent()).collect();
+
+	for id in ids {
+		let instance = canonical.get_by_ref_mut(id).unwrap();
+
+		for value in instance.properties.values_mut() {
+			if let Variant::Ref(target) = value {
+				*target = refs.get(target).copied().unwrap_or_else(Ref::none);
+			}
+		}
+	}
+
+	(canonical, new_roots)
+}
+
+fn build(dom: &WeakDom, id: Ref, path: &str, refs: &mut HashMap<Ref, Ref>) -> InstanceBuilder {
+	let instance = dom.get_by_ref(id).unwrap();
+	let referent = stable_ref(path);
+
+	refs.insert(id, referent);
+
+	let mut builder = InstanceBuilder::with_property_capacity(instance.class, instance.properties.len())
+		.with_name(&instance.name)
+		.with_referent(referent)
+		.with_properties(instance.properties.iter().map(|(key, value)| (*key, value.clone())));
+
+	for (child, path) in sorted(dom, instance.children(), path) {
+		builder.add_child(build(dom, child, &path, refs));
+	}
+
+	builder
+}
+
+/// Sort instances by name and class, keeping the original order of identical
+/// ones, and return them with their paths, numbered if they are not unique
+fn sorted(dom: &WeakDom, ids: &[Ref], parent_path: &str) -> Vec<(Ref, String)> {
+	let mut instances: Vec<_> = ids.iter().map(|id| dom.get_by_ref(*id).unwrap()).collect();
+	instances.sort_by(|a, b| (&a.name, a.class).cmp(&(&b.name, b.class)));
+
+	let mut counts: HashMap<String, usize> = HashMap::new();
+
+	instances
+		.into_iter()
+		.map(|instance| {
+			let path = format!("{}/{}.{}", parent_path, instance.name, instance.class);
+			let count = counts.entry(path.clone()).or_default();
+
+			*count += 1;
+
+			let path = if *count > 1 {
+				format!("{}#{}", path, count)
+			} else {
+				path
+			};
+
+			(instance.referent(), path)
+		})
+		.collect()
+}
+
+fn stable_ref(path: &str) -> Ref {
+	Ref::from_str(&util::hash(path)[..32]).unwrap_or_else(|_| Ref::new())
+}
@@ -4,8 +4,9 @@ use rbx_dom_weak::{types::Ref, Ustr};
 use serde::Serialize;
 use snapshot::AddedSnapshot;
 use std::{
+	collections::BTreeSet,
 	fs::File,
-	io::BufWriter,
+	io::{BufWriter, Write},
 	path::{Path, PathBuf},
 	sync::{Arc, Mutex, MutexGuard},
 };
@@ -18,6 +19,7 @@ use self::{
 };
 use crate::{core::snapshot::Snapshot, lock, middleware::new_snapshot, project::Project, stats, util, vfs::Vfs};
 
+pub mod canonical;
 pub mod changes;
 pub mod helpers;
 pub mod meta;
@@ -152,9 +154,22 @@ impl Core {
 		)
 	}
 
-	/// Build the tree into a file, either XML or binary
-	pub fn build(&self, path: &Path, xml: bool) -> Result<()> {
-		let writer = BufWriter::new(File::create(path)?);
+	/// Build the tree into a file, either XML or binary,
+	/// returns the content hash of the written file
+	pub fn build(&self, path: &Path, xml: bool) -> Result<String> {
+		let mut data = Vec::new();
+		self.write(&mut data, xml)?;
+
+		BufWriter::new(File::create(path)?).write_all(&data)?;
+
+		stats::projects_built(1);
+
+		Ok(util::hash(data))
+	}
+
+	/// Serialize the tree into the `writer`, either XML or binary,
+	/// the output is always the same for the same project sources
+	pub fn write(&self, writer: impl Write, xml: bool) -> Result<()> {
 		let tree = lock!(&self.tree);
 
 		let root_refs = if self.project().is_place() {
@@ -163,17 +178,37 @@ impl Core {
 			vec![tree.root_ref()]
 		};
 
+		let (dom, root_refs) = canonical::canonicalize(tree.inner(), &root_refs);
+
 		if xml {
-			rbx_xml::to_writer_default(writer, tree.inner(), &root_refs)?;
+			rbx_xml::to_writer_default(writer, &dom, &root_refs)?;
 		} else {
-			rbx_binary::to_writer(writer, tree.inner(), &root_refs)?;
+			rbx_binary::to_writer(writer, &dom, &root_refs)?;
 		}
 
-		stats::projects_built(1);
-
 		Ok(())
 	}
 
+	/// Returns sorted paths of all files the tree was built from
+	pub fn source_files(&self) -> Vec<PathBuf> {
+		let tree = lock!(&self.tree);
+		let mut files = BTreeSet::new();
+
+		for instance in tree.inner().descendants() {
+			let Some(meta) = tree.get_meta(instance.referent()) else {
+				continue;
+			};
+
+			for entry in meta.source.relevant() {
+				if !matches!(entry, SourceEntry::Folder(_)) {
+					files.insert(entry.path().to_owned());
+				}
+			}
+		}
+
+		files.into_iter().collect()
+	}
+
 	/// Write sourcemap of the tree
 	pub fn sourcemap(&self, path: Option<PathBuf>, non_scripts: bool) -> Result<()> {
 		let tree = lock!(&self.tree);
@@ -1,10 +1,11 @@
 use anyhow::Result;
 use colored::Colorize;
-use crossbeam_channel::{select, Sender};
+use crossbeam_channel::{select, Receiver, Sender};
 use log::{debug, error, info, trace, warn};
 use serde::Deserialize;
 use std::{
-	sync::{Arc, Mutex},
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex, Weak},
 	thread::Builder,
 };
 
@@ -12,10 +13,11 @@ use super::{changes::Changes, queue::Queue, tree::Tree};
 use crate::{
 	argon_error,
 	config::Config,
-	constants::BLACKLISTED_PATHS,
+	constants::{BLACKLISTED_PATHS, VALIDATION_DEBOUNCE_TIME},
 	lock, logger,
 	project::{Project, ProjectDetails},
 	server, stats,
+	validation::{checks::budgets, report::Report, validator::Validator},
 	vfs::{Vfs, VfsEvent},
 };
 
@@ -27,10 +29,14 @@ pub mod write;
 pub struct WriteRequest {
 	pub changes: Changes,
 	pub client_id: u32,
+	/// Session token, when not sent in the header
+	#[serde(default)]
+	pub token: Option<String>,
 }
 
 pub struct Processor {
 	writer: Sender<WriteRequest>,
+	handler: Arc<Handler>,
 }
 
 impl Processor {
@@ -40,11 +46,17 @@ impl Processor {
 			tree,
 			vfs: vfs.clone(),
 			project,
+			validator: Mutex::new(None),
+			report: Mutex::new(None),
 		});
 
-		let handler = handler.clone();
 		let (sender, receiver) = crossbeam_channel::unbounded();
 
+		let processor = Self {
+			writer: sender,
+			handler: handler.clone(),
+		};
+
 		Builder::new()
 			.name("processor".to_owned())
 			.spawn(move || -> Result<()> {
@@ -54,7 +66,10 @@ impl Processor {
 				loop {
 					select! {
 						recv(vfs_receiver) -> event => {
-							handler.on_vfs_event(event?);
+							let event = event?;
+
+							handler.request_validation(event.path());
+							handler.on_vfs_event(event);
 						}
 						recv(client_receiver) -> request => {
 							vfs.pause();
@@ -66,12 +81,38 @@ impl Processor {
 			})
 			.unwrap();
 
-		Self { writer: sender }
+		processor
 	}
 
 	pub fn write(&self, request: WriteRequest) {
 		self.writer.send(request).unwrap();
 	}
+
+	/// Validate the project after changes on a separate thread
+	/// and push the report to all clients when diagnostics change
+	pub fn enable_validation(&self) {
+		let mut validator = lock!(self.handler.validator);
+
+		if validator.is_some() {
+			return;
+		}
+
+		let (sender, receiver) = crossbeam_channel::unbounded();
+		let handler = Arc::downgrade(&self.handler);
+
+		Builder::new()
+			.name("validator".to_owned())
+			.spawn(move || Handler::run_validation(handler, receiver))
+			.unwrap();
+
+		*validator = Some(sender);
+	}
+
+	/// Returns the last validation report, `None` if live
+	/// validation is disabled or the first one is not ready yet
+	pub fn validation_report(&self) -> Option<Report> {
+		lock!(self.handler.report).clone()
+	}
 }
 
 struct Handler {
@@ -79,6 +120,9 @@ struct Handler {
 	tree: Arc<Mutex<Tree>>,
 	vfs: Arc<Vfs>,
 	project: Arc<Mutex<Project>>,
+	/// Sends changed paths to the validator thread, `None` until validation is enabled
+	validator: Mutex<Option<Sender<PathBuf>>>,
+	report: Mutex<Option<Report>>,
 }
 
 impl Handler {
@@ -168,7 +212,6 @@ impl Handler {
 				}
 			} else if let VfsEvent::Delete(_) = event {
 				argon_error!("Warning! Top level project file was deleted. This might cause unexpected behavior. Skipping processing of changes!");
-				return;
 			}
 		}
 	}
@@ -233,4 +276,80 @@ impl Handler {
 
 		self.queue.push(server::SyncbackChanges(), Some(0)).ok();
 	}
+
+	fn request_validation(&self, path: &Path) {
+		if let Some(validator) = lock!(self.validator).as_ref() {
+			validator.send(path.to_owned()).ok();
+		}
+	}
+
+	/// Validate the whole project first, then only the files changed since, once
+	/// the changes stop coming for `VALIDATION_DEBOUNCE_TIME`. Runs until the
+	/// handler is dropped together with the sender, never blocks the processor
+	fn run_validation(handler: Weak<Handler>, receiver: Receiver<PathBuf>) {
+		let mut changed = None;
+
+		loop {
+			match handler.upgrade() {
+				Some(handler) => handler.on_validate(changed),
+				None => break,
+			}
+
+			let Ok(path) = receiver.recv() else {
+				break;
+			};
+
+			let mut paths = vec![path];
+
+			while let Ok(path) = receiver.recv_timeout(VALIDATION_DEBOUNCE_TIME) {
+				paths.push(path);
+			}
+
+			changed = Some(paths);
+		}
+
+		trace!("Validator stopped");
+	}
+
+	fn validate(&self, changed: Option<Vec<PathBuf>>) -> Report {
+		let workspace_dir = lock!(self.project).workspace_dir.clone();
+
+		// Budgets need a full build so they are skipped here
+		let validator = Validator::new().without(budgets::RULE);
+
+		// Results of unchanged files are taken from the cache,
+		// changes outside of the workspace need a full validation
+		let changed = changed.and_then(|changed| {
+			changed
+				.iter()
+				.map(|path| path.strip_prefix(&workspace_dir).ok().map(Path::to_owned))
+				.collect::<Option<Vec<_>>>()
+		});
+
+		match changed {
+			Some(changed) => validator.only_changed(changed).run(&workspace_dir),
+			None => validator.run(&workspace_dir),
+		}
+	}
+
+	#[profiling::function]
+	fn on_validate(&self, changed: Option<Vec<PathBuf>>) {
+		let report = self.validate(changed);
+		let mut last_report = lock!(self.report);
+		if last_report
+			.as_ref()
+			.is_some_and(|last_report| last_report.diagnostics == report.diagnostics)
+		{
+			trace!("Validation diagnostics did not change");
+			return;
+		}
+
+		// Cached before pushing, so clients subscribing meanwhile get it either way
+		*last_report = Some(report.clone());
+
+		match self.queue.push(server::Diagnostics(report), None) {
+			Ok(()) => trace!("Added diagnostics to the queue"),
+			Err(err) => error!("Failed to add diagnostics to the queue: {}", err),
+		}
+	}
 }
@@ -2,6 +2,7 @@ use anyhow::{bail, Result};
 use colored::Colorize;
 use crossbeam_channel::{Receiver, Sender};
 use std::{collections::HashMap, sync::RwLock};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 use crate::{
 	argon_warn,
@@ -33,6 +34,39 @@ struct Listener {
 struct Channel {
 	sender: Sender<Message>,
 	receiver: Receiver<Message>,
+	/// Set while the listener is connected over WebSocket
+	stream: Option<UnboundedSender<Message>>,
+}
+
+impl Channel {
+	fn new() -> Self {
+		let (sender, receiver) = crossbeam_channel::unbounded();
+
+		Self {
+			sender,
+			receiver,
+			stream: None,
+		}
+	}
+
+	/// Forward to the stream if there is one, messages go back
+	/// to the regular queue once the stream is closed
+	fn send(&mut self, message: Message) -> Result<()> {
+		let message = match &self.stream {
+			Some(stream) => match stream.send(message) {
+				Ok(()) => return Ok(()),
+				Err(err) => {
+					self.stream = None;
+					err.0
+				}
+			},
+			None => message,
+		};
+
+		self.sender.send(message)?;
+
+		Ok(())
+	}
 }
 
 #[derive(Debug)]
@@ -60,10 +94,8 @@ impl Queue {
 				bail!("Not subscribed")
 			}
 
-			let queues = read!(self.queues);
-			let sender = queues.get(&id).unwrap().sender.clone();
-
-			sender.send(message.into())?;
+			let mut queues = write!(self.queues);
+			queues.get_mut(&id).unwrap().send(message.into())?;
 
 			return Ok(());
 		}
@@ -72,15 +104,14 @@ impl Queue {
 		let mut did_push = false;
 
 		for listener in read!(self.listeners).iter() {
-			let queues = read!(self.queues);
-			let sender = queues.get(&listener.id).unwrap().sender.clone();
-
-			sender.send(message.clone())?;
+			let mut queues = write!(self.queues);
+			queues.get_mut(&listener.id).unwrap().send(message.clone())?;
 
 			did_push = true;
 		}
 
-		if !did_push {
+		// Diagnostics are recomputed on every change so there is no point in counting them
+		if !did_push && !matches!(message, Message::Diagnostics(_)) {
 			let max_unsynced_changes = Config::new().max_unsynced_changes;
 			let mut unsynced_changes = write!(self.unsynced_changes);
 
@@ -142,13 +173,33 @@ impl Queue {
 		})
 	}
 
+	/// Deliver messages of `id` through the returned receiver instead of
+	/// `get` calls, starting with the ones still waiting in the queue
+	pub fn stream(&self, id: u32) -> Result<UnboundedReceiver<Message>> {
+		if !self.is_subscribed(id) {
+			bail!("Not subscribed")
+		}
+
+		let mut queues = write!(self.queues);
+		let channel = queues.get_mut(&id).unwrap();
+
+		let (sender, receiver) = mpsc::unbounded_channel();
+
+		for message in channel.receiver.try_iter() {
+			sender.send(message)?;
+		}
+
+		channel.stream = Some(sender);
+
+		Ok(receiver)
+	}
+
 	pub fn subscribe(&self, id: u32, name: &str) -> Result<()> {
 		if self.is_subscribed(id) {
 			bail!("Already subscribed")
 		}
 
-		let (sender, receiver) = crossbeam_channel::unbounded();
-		let channel = Channel { sender, receiver };
+		let channel = Channel::new();
 
 		let listener = Listener {
 			id,
@@ -173,8 +224,7 @@ impl Queue {
 			id += 1;
 		}
 
-		let (sender, receiver) = crossbeam_channel::unbounded();
-		let channel = Channel { sender, receiver };
+		let channel = Channel::new();
 
 		let listener = Listener {
 			id,
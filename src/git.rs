@@ -0,0 +1,105 @@
+use anyhow::Result;
+use log::trace;
+use std::path::Path;
+
+use crate::program::{Program, ProgramName};
+
+/// Whether `dir` or any of its ancestors is a Git repository
+pub fn is_repo(dir: &Path) -> bool {
+	dir.ancestors().any(|dir| dir.join(".git").exists())
+}
+
+/// Returns hash of the `HEAD` commit, `None` if `dir`
+/// is not a Git repository or it has no commits yet
+pub fn head_commit(dir: &Path) -> Result<Option<String>> {
+	run(dir, &["rev-parse", "HEAD"])
+}
+
+/// Returns the most recent tag reachable from `HEAD`
+pub fn last_tag(dir: &Path) -> Result<Option<String>> {
+	run(dir, &["describe", "--tags", "--abbrev=0"])
+}
+
+/// Whether `revision` exists and points to a commit
+pub fn has_commit(dir: &Path, revision: &str) -> Result<bool> {
+	Ok(run(dir, &["cat-file", "-e", &format!("{}^{{commit}}", revision)])?.is_some())
+}
+
+/// Whether `ancestor` is reachable from `revision`
+pub fn is_ancestor(dir: &Path, ancestor: &str, revision: &str) -> Result<bool> {
+	Ok(run(dir, &["merge-base", "--is-ancestor", ancestor, revision])?.is_some())
+}
+
+/// Returns commits reachable from `HEAD` but not from `since`, newest first,
+/// skipping merges. Empty if `dir` is not a Git repository
+pub fn commits(dir: &Path, since: Option<&str>) -> Result<Vec<Commit>> {
+	let range = match since {
+		Some(since) => format!("{}..HEAD", since),
+		None => String::from("HEAD"),
+	};
+
+	let Some(output) = run(dir, &["log", "--no-merges", "--format=%h%x1f%s%x1f%b%x1e", &range])? else {
+		return Ok(vec![]);
+	};
+
+	let commits = output
+		.split('\x1e')
+		.filter_map(|record| {
+			let mut fields = record.trim_start_matches('\n').splitn(3, '\x1f');
+
+			Some(Commit {
+				hash: fields.next().filter(|hash| !hash.is_empty())?.to_owned(),
+				subject: fields.next()?.trim().to_owned(),
+				body: fields.next().unwrap_or_default().trim().to_owned(),
+			})
+		})
+		.collect();
+
+	Ok(commits)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Commit {
+	/// Abbreviated commit hash
+	pub hash: String,
+	pub subject: String,
+	pub body: String,
+}
+
+/// Returns value of the Git config `key` as seen from `dir`,
+/// works outside of repositories with the global config
+pub fn config(dir: &Path, key: &str) -> Result<Option<String>> {
+	let value = output(dir, &["config", "--get", key])?;
+
+	Ok(value.filter(|value| !value.is_empty()))
+}
+
+/// Run Git in `dir` and return its trimmed output if it succeeded
+fn run(dir: &Path, args: &[&str]) -> Result<Option<String>> {
+	if !is_repo(dir) {
+		return Ok(None);
+	}
+
+	output(dir, args)
+}
+
+fn output(dir: &Path, args: &[&str]) -> Result<Option<String>> {
+	let output = Program::new(ProgramName::Git)
+		.message("Failed to read Git repository")
+		.current_dir(dir)
+		.args(args.iter().copied())
+		.output_captured()?;
+
+	match output {
+		Some(output) if output.status.success() => Ok(Some(String::from_utf8_lossy(&output.stdout).trim().to_owned())),
+		Some(output) => {
+			trace!(
+				"Git {:?} failed: {}",
+				args,
+				String::from_utf8_lossy(&output.stderr).trim()
+			);
+			Ok(None)
+		}
+		None => Ok(None),
+	}
+}
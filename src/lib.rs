@@ -8,20 +8,25 @@ pub mod constants;
 pub mod core;
 pub mod crash_handler;
 pub mod ext;
+pub mod git;
 pub mod glob;
 pub mod installer;
 pub mod integration;
 pub mod logger;
 pub mod middleware;
+pub mod plugin;
 pub mod program;
 pub mod project;
+pub mod publish;
 pub mod resolution;
 pub mod server;
 pub mod sessions;
 pub mod stats;
 pub mod studio;
+pub mod sync;
 pub mod updater;
 pub mod util;
+pub mod validation;
 pub mod vfs;
 pub mod workspace;
 
@@ -9,7 +9,7 @@ use std::{
 	thread,
 };
 
-use argon::{argon_error, cli::Cli, config::Config, crash_handler, installer, logger, stats, updater};
+use fluxo::{argon_error, cli::Cli, config::Config, crash_handler, installer, logger, stats, updater};
 
 const PROFILER_ADDRESS: &str = "localhost:8888";
 
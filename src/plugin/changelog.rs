@@ -0,0 +1,147 @@
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
+use std::{fs, path::Path};
+
+use crate::ext::ResultExt;
+
+/// Name of the plugin changelog file
+pub const CHANGELOG_FILE: &str = "CHANGELOG.md";
+
+/// Insert a dated section of `version` into the changelog `contents`, moving
+/// all entries of the unreleased section into it, followed by the `notes`
+pub fn release(contents: &str, version: &str, date: NaiveDate, notes: Option<&str>) -> Result<String> {
+	let lines: Vec<&str> = contents.lines().collect();
+
+	let heading = format!("## [{}]", version);
+
+	if lines.iter().any(|line| is_heading(line, version)) {
+		bail!("{} already has a section for version {}", CHANGELOG_FILE, version);
+	}
+
+	let is_section = |line: &&str| line.starts_with("## ");
+
+	let unreleased = lines
+		.iter()
+		.position(|line| is_section(line) && line.to_lowercase().contains("unreleased"));
+
+	// Index of the line where the new section starts
+	let (start, entries) = match unreleased {
+		Some(index) => {
+			let end = lines[index + 1..]
+				.iter()
+				.position(is_section)
+				.map(|offset| index + 1 + offset)
+				.unwrap_or(lines.len());
+
+			let entries: Vec<&str> = lines[index + 1..end]
+				.iter()
+				.copied()
+				.filter(|line| !line.trim().is_empty() && line.trim() != "-")
+				.collect();
+
+			(end, entries)
+		}
+		None => (lines.iter().position(is_section).unwrap_or(lines.len()), vec![]),
+	};
+
+	let mut section = vec![format!("{} - {}", heading, date.format("%Y-%m-%d")), String::new()];
+
+	for line in entries {
+		push_line(&mut section, line.to_owned());
+	}
+
+	if let Some(notes) = notes {
+		for line in notes.lines().filter(|line| !line.trim().is_empty()) {
+			if line.starts_with(['-', '*', '#']) {
+				push_line(&mut section, line.to_owned());
+			} else {
+				push_line(&mut section, format!("- {}", line));
+			}
+		}
+	}
+
+	// Entries are separated from the next section by an empty line
+	if section.len() > 2 {
+		section.push(String::new());
+	}
+
+	let mut result: Vec<String> = Vec::new();
+
+	for (index, line) in lines.iter().enumerate() {
+		if index == start {
+			result.append(&mut section);
+		}
+
+		// Unreleased entries are moved to the new section
+		if let Some(unreleased) = unreleased.filter(|unreleased| index > *unreleased && index < start) {
+			if index == unreleased + 1 {
+				result.push(String::new());
+			}
+
+			continue;
+		}
+
+		result.push(line.to_string());
+	}
+
+	if !section.is_empty() {
+		if result.last().is_some_and(|line| !line.is_empty()) {
+			result.push(String::new());
+		}
+
+		result.extend(section);
+	}
+
+	Ok(result.join("\n").trim_end().to_owned() + "\n")
+}
+
+/// Push `line` to the `section`, surrounding headings with empty lines
+fn push_line(section: &mut Vec<String>, line: String) {
+	if !line.starts_with('#') {
+		section.push(line);
+		return;
+	}
+
+	if section.last().is_some_and(|last| !last.is_empty()) {
+		section.push(String::new());
+	}
+
+	section.push(line);
+	section.push(String::new());
+}
+
+/// Update the changelog in `workspace_dir`, creating it if it does not exist
+pub fn update(workspace_dir: &Path, version: &str, date: NaiveDate, notes: Option<&str>) -> Result<()> {
+	let path = workspace_dir.join(CHANGELOG_FILE);
+
+	let contents = if path.exists() {
+		fs::read_to_string(&path).with_desc(|| format!("Failed to read {}", path.display()))?
+	} else {
+		String::from("# Changelog\n\n## Unreleased Changes\n")
+	};
+
+	let contents = release(&contents, version, date, notes)?;
+
+	fs::write(&path, contents).with_desc(|| format!("Failed to write {}", path.display()))
+}
+
+/// Returns the section of `version` in the changelog `contents`, including its heading
+pub fn excerpt(contents: &str, version: &str) -> Option<String> {
+	let mut lines = contents.lines();
+
+	let heading = lines.find(|line| is_heading(line, version))?;
+
+	let body: Vec<&str> = lines.take_while(|line| !line.starts_with("## ")).collect();
+
+	Some(format!("{}\n{}", heading, body.join("\n")).trim_end().to_owned() + "\n")
+}
+
+/// Returns whether `line` is the heading of `version`, like `## [1.0.0] - 2025-01-01`
+/// or `## 1.0.0`, but not of its prereleases like `## 1.0.0-beta.1`
+fn is_heading(line: &str, version: &str) -> bool {
+	let headings = [format!("## [{}]", version), format!("## {}", version)];
+
+	headings
+		.iter()
+		.any(|heading| line.starts_with(heading.as_str()) && !line[heading.len()..].starts_with(['.', '-', '+']))
+}
@@ -0,0 +1,78 @@
+use anyhow::{bail, Result};
+use semver::{Prerelease, Version};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::metadata::PluginMetadata;
+use crate::ext::ResultExt;
+
+/// Release channel like `beta` or `stable`, defined in `publishing.channels`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+	/// Asset published to, overrides `publishing.assetId`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub asset_id: Option<u64>,
+	/// Appended to the plugin name, like ` (Beta)`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name_suffix: Option<String>,
+	/// Prerelease identifier all versions in this channel must have,
+	/// channels without it only accept stable versions
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prerelease: Option<String>,
+	/// Fields of `plugin.meta.json` replaced when publishing to this channel
+	#[serde(default, skip_serializing_if = "Map::is_empty")]
+	pub metadata: Map<String, Value>,
+}
+
+impl Channel {
+	/// Returns `metadata` with overrides and name suffix of the channel applied
+	pub fn apply(&self, metadata: &PluginMetadata) -> Result<PluginMetadata> {
+		let mut metadata = if self.metadata.is_empty() {
+			metadata.clone()
+		} else {
+			let mut value = serde_json::to_value(metadata)?;
+
+			if let Value::Object(map) = &mut value {
+				map.extend(self.metadata.clone());
+			}
+
+			serde_json::from_value(value).desc("Channel metadata overrides are invalid")?
+		};
+
+		if let Some(suffix) = &self.name_suffix {
+			metadata.name.push_str(suffix);
+		}
+
+		Ok(metadata)
+	}
+
+	/// Whether `version` can be published to this channel
+	pub fn accepts(&self, version: &Version) -> bool {
+		match &self.prerelease {
+			Some(tag) => version.pre.as_str().split('.').next() == Some(tag.as_str()),
+			None => version.pre.is_empty(),
+		}
+	}
+
+	/// Returns `version` adjusted for this channel: stable versions get the `.1`
+	/// prerelease of the channel, prereleases are released in stable channels
+	pub fn version(&self, version: &Version) -> Result<Version> {
+		if self.accepts(version) {
+			return Ok(version.clone());
+		}
+
+		let mut version = version.clone();
+
+		match &self.prerelease {
+			Some(tag) if version.pre.is_empty() => {
+				version.pre = Prerelease::new(&format!("{}.1", tag))
+					.with_desc(|| format!("'{}' is not a valid prerelease identifier", tag))?;
+			}
+			Some(tag) => bail!("Version {} does not have the '{}' prerelease identifier", version, tag),
+			None => version.pre = Prerelease::EMPTY,
+		}
+
+		Ok(version)
+	}
+}
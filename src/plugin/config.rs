@@ -0,0 +1,166 @@
+use anyhow::{bail, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+use super::{channel::Channel, team::TeamConfig, CONFIG_FILE};
+use crate::{
+	ext::ResultExt,
+	glob::Glob,
+	validation::{banned_apis::DEFAULT_BANNED_APIS, Severity},
+};
+
+/// Contents of the `fluxo.config.json` file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginConfig {
+	#[serde(default)]
+	pub validation: ValidationConfig,
+	#[serde(default)]
+	pub publishing: PublishingConfig,
+	#[serde(default)]
+	pub team: TeamConfig,
+}
+
+impl PluginConfig {
+	/// Load config from the given workspace directory,
+	/// falling back to defaults if the file does not exist
+	pub fn load(workspace_dir: &Path) -> Result<Self> {
+		let path = workspace_dir.join(CONFIG_FILE);
+
+		if !path.exists() {
+			return Ok(Self::default());
+		}
+
+		serde_json::from_str(&fs::read_to_string(&path)?)
+			.with_desc(|| format!("Failed to parse {}", path.display().to_string().bold()))
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationConfig {
+	#[serde(default = "default_banned_apis")]
+	pub banned_apis: Vec<String>,
+	#[serde(default)]
+	pub custom_rules: Vec<CustomRule>,
+
+	/// Only files matching these globs are validated
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub include: Vec<Glob>,
+	/// Files matching these globs are never validated
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub exclude: Vec<Glob>,
+
+	/// Limits checked against the built project, budgets
+	/// are checked by default only when this is set
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub budgets: Option<BudgetConfig>,
+}
+
+impl Default for ValidationConfig {
+	fn default() -> Self {
+		Self {
+			banned_apis: default_banned_apis(),
+			custom_rules: Vec::new(),
+			include: Vec::new(),
+			exclude: Vec::new(),
+			budgets: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BudgetConfig {
+	/// Maximum number of characters in a single script
+	pub max_source_length: usize,
+	/// Maximum number of instances in the plugin
+	pub max_instances: usize,
+	/// Maximum number of lines of code in the plugin
+	pub max_loc: usize,
+	/// Maximum size of the plugin serialized as `.rbxm`, in bytes
+	pub max_size: usize,
+}
+
+impl Default for BudgetConfig {
+	fn default() -> Self {
+		Self {
+			max_source_length: 200_000,
+			max_instances: 10_000,
+			max_loc: 50_000,
+			max_size: 10 * 1024 * 1024,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishingConfig {
+	/// Asset updated by Open Cloud publishing, a new asset is created when not set
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub asset_id: Option<u64>,
+	/// Owner of the asset created by Open Cloud publishing
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub creator: Option<Creator>,
+	/// Base URL of the Open Cloud API, `https://apis.roblox.com` by default
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_url: Option<String>,
+	/// Release channels, selected with `fluxo publish --channel`
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub channels: BTreeMap<String, Channel>,
+}
+
+impl PublishingConfig {
+	/// Returns the channel called `name`, failing with the list of available ones
+	pub fn channel(&self, name: &str) -> Result<&Channel> {
+		match self.channels.get(name) {
+			Some(channel) => Ok(channel),
+			None if self.channels.is_empty() => bail!(
+				"Channel {} does not exist, no channels are defined in publishing.channels",
+				name.bold()
+			),
+			None => bail!(
+				"Channel {} does not exist, available channels: {}",
+				name.bold(),
+				self.channels.keys().cloned().collect::<Vec<_>>().join(", ")
+			),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Creator {
+	UserId(u64),
+	GroupId(u64),
+}
+
+/// User defined rule, matching either a `regex`
+/// against raw source or a Luau token `pattern`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomRule {
+	pub id: String,
+	pub message: String,
+	#[serde(default = "default_severity")]
+	pub severity: Severity,
+
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub regex: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub pattern: Option<String>,
+
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub include: Vec<Glob>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub exclude: Vec<Glob>,
+}
+
+fn default_banned_apis() -> Vec<String> {
+	DEFAULT_BANNED_APIS.iter().map(|api| api.to_string()).collect()
+}
+
+fn default_severity() -> Severity {
+	Severity::Warning
+}
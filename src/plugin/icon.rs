@@ -0,0 +1,258 @@
+use anyhow::{bail, Result};
+use png::{BitDepth, ColorType, Decoder, Encoder, Transformations};
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+use super::metadata::{PluginMetadata, ICON_ASSET_PREFIX};
+use crate::{
+	ext::ResultExt,
+	validation::{Diagnostic, Severity},
+};
+
+pub const RULE: &str = "icon";
+
+/// Icon used when `plugin.meta.json` does not specify one
+pub const ICON_FILE: &str = "plugin.icon.png";
+
+/// Width and height of the icon shown in the Creator Store
+pub const ICON_SIZE: u32 = 512;
+
+/// Largest icon file accepted by the Creator Store, in bytes
+pub const MAX_ICON_BYTES: usize = 1024 * 1024;
+
+/// Sizes of the generated variants: the Creator Store
+/// thumbnail and the toolbar button at 2x and 1x scale
+pub const VARIANT_SIZES: [u32; 3] = [ICON_SIZE, 64, 32];
+
+/// Directory where icon variants are written, relative to the workspace
+pub const ICONS_DIR: &str = ".fluxo/icons";
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Returns the path of the local plugin icon relative to the workspace, either the
+/// one set in `metadata` or `plugin.icon.png`, `None` for asset IDs and missing icons
+pub fn locate(workspace_dir: &Path, metadata: Option<&PluginMetadata>) -> Option<String> {
+	match metadata.and_then(|metadata| metadata.icon.as_deref()).map(str::trim) {
+		Some(icon) if !icon.is_empty() => {
+			if icon.starts_with(ICON_ASSET_PREFIX) || !workspace_dir.join(icon).is_file() {
+				None
+			} else {
+				Some(icon.to_owned())
+			}
+		}
+		_ => workspace_dir.join(ICON_FILE).is_file().then(|| ICON_FILE.to_owned()),
+	}
+}
+
+/// Decoded icon with 8-bit RGBA pixels
+#[derive(Debug, Clone, PartialEq)]
+pub struct Icon {
+	pub width: u32,
+	pub height: u32,
+	/// Size of the encoded file in bytes
+	pub size: usize,
+	pixels: Vec<u8>,
+}
+
+impl Icon {
+	pub fn load(path: &Path) -> Result<Self> {
+		let data = fs::read(path).with_desc(|| format!("Failed to read icon {}", path.display()))?;
+		Self::decode(&data)
+	}
+
+	pub fn decode(data: &[u8]) -> Result<Self> {
+		if !data.starts_with(&PNG_SIGNATURE) {
+			bail!("File is not a PNG image");
+		}
+
+		let mut decoder = Decoder::new(data);
+		decoder.set_transformations(Transformations::normalize_to_color8());
+
+		let mut reader = decoder.read_info().desc("Invalid PNG image")?;
+		let mut buffer = vec![0; reader.output_buffer_size()];
+
+		let info = reader.next_frame(&mut buffer).desc("Invalid PNG image")?;
+		buffer.truncate(info.buffer_size());
+
+		let pixels = match info.color_type {
+			ColorType::Rgba => buffer,
+			ColorType::Rgb => buffer.chunks(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+			ColorType::GrayscaleAlpha => buffer.chunks(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+			ColorType::Grayscale => buffer.iter().flat_map(|p| [*p, *p, *p, 255]).collect(),
+			ColorType::Indexed => bail!("Indexed PNG images are not supported"),
+		};
+
+		Ok(Self {
+			width: info.width,
+			height: info.height,
+			size: data.len(),
+			pixels,
+		})
+	}
+
+	pub fn encode(&self) -> Result<Vec<u8>> {
+		let mut data = Vec::new();
+
+		let mut encoder = Encoder::new(&mut data, self.width, self.height);
+		encoder.set_color(ColorType::Rgba);
+		encoder.set_depth(BitDepth::Eight);
+
+		let mut writer = encoder.write_header()?;
+		writer.write_image_data(&self.pixels)?;
+		writer.finish()?;
+
+		Ok(data)
+	}
+
+	/// Returns the icon scaled to `size`x`size`, averaging covered pixels weighted by alpha
+	pub fn resize(&self, size: u32) -> Self {
+		let scale_x = self.width as f64 / size as f64;
+		let scale_y = self.height as f64 / size as f64;
+
+		let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+
+		for target_y in 0..size {
+			let (y0, y1) = (target_y as f64 * scale_y, (target_y + 1) as f64 * scale_y);
+
+			for target_x in 0..size {
+				let (x0, x1) = (target_x as f64 * scale_x, (target_x + 1) as f64 * scale_x);
+
+				let mut color = [0.0; 3];
+				let mut alpha = 0.0;
+				let mut total = 0.0;
+
+				for y in y0.floor() as u32..(y1.ceil() as u32).min(self.height) {
+					let weight_y = y1.min(y as f64 + 1.0) - y0.max(y as f64);
+
+					for x in x0.floor() as u32..(x1.ceil() as u32).min(self.width) {
+						let weight = weight_y * (x1.min(x as f64 + 1.0) - x0.max(x as f64));
+						let index = ((y * self.width + x) * 4) as usize;
+						let pixel_alpha = self.pixels[index + 3] as f64 * weight;
+
+						for (channel, value) in color.iter_mut().enumerate() {
+							*value += self.pixels[index + channel] as f64 * pixel_alpha;
+						}
+
+						alpha += pixel_alpha;
+						total += weight;
+					}
+				}
+
+				for value in color {
+					let value = if alpha > 0.0 { value / alpha } else { 0.0 };
+					pixels.push(value.round().clamp(0.0, 255.0) as u8);
+				}
+
+				let alpha = if total > 0.0 { alpha / total } else { 0.0 };
+				pixels.push(alpha.round().clamp(0.0, 255.0) as u8);
+			}
+		}
+
+		Self {
+			width: size,
+			height: size,
+			size: 0,
+			pixels,
+		}
+	}
+
+	/// Check the icon against Creator Store rules
+	pub fn validate(&self) -> Vec<(Severity, String)> {
+		let mut problems = Vec::new();
+
+		if self.size > MAX_ICON_BYTES {
+			problems.push((
+				Severity::Error,
+				format!(
+					"Icon is {} KB, larger than the {} KB limit",
+					self.size.div_ceil(1024),
+					MAX_ICON_BYTES / 1024
+				),
+			));
+		}
+
+		if self.width != self.height {
+			problems.push((
+				Severity::Error,
+				format!("Icon must be square, it is {}x{}", self.width, self.height),
+			));
+		} else if self.width < ICON_SIZE {
+			problems.push((
+				Severity::Warning,
+				format!(
+					"Icon is {}x{}, smaller than {}x{}, it will look blurry in the Creator Store",
+					self.width, self.height, ICON_SIZE, ICON_SIZE
+				),
+			));
+		} else if self.width > ICON_SIZE {
+			problems.push((
+				Severity::Info,
+				format!(
+					"Icon is {}x{}, it will be resized to {}x{}",
+					self.width, self.height, ICON_SIZE, ICON_SIZE
+				),
+			));
+		}
+
+		problems
+	}
+
+	/// Write all variants to `dir` as `plugin.icon.<size>.png`, returns their paths
+	pub fn write_variants(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+		fs::create_dir_all(dir)?;
+
+		let mut paths = Vec::new();
+
+		for size in VARIANT_SIZES {
+			let path = dir.join(format!("plugin.icon.{}.png", size));
+
+			let data = if self.width == size && self.height == size {
+				self.encode()?
+			} else {
+				self.resize(size).encode()?
+			};
+
+			fs::write(&path, data).with_desc(|| format!("Failed to write {}", path.display()))?;
+			paths.push(path);
+		}
+
+		Ok(paths)
+	}
+}
+
+/// Check the plugin icon, warning when there is none
+pub fn validate(workspace_dir: &Path, metadata: Option<&PluginMetadata>) -> Vec<Diagnostic> {
+	let configured = metadata.and_then(|metadata| metadata.icon.as_deref()).map(str::trim);
+
+	let Some(icon) = locate(workspace_dir, metadata) else {
+		// Asset IDs and missing files are reported by the metadata check
+		if configured.is_some_and(|icon| !icon.is_empty()) {
+			return Vec::new();
+		}
+
+		return vec![Diagnostic::new(
+			RULE,
+			Severity::Warning,
+			format!(
+				"Plugin has no icon, add a {}x{} {} to the project",
+				ICON_SIZE, ICON_SIZE, ICON_FILE
+			),
+		)
+		.with_path(Path::new(ICON_FILE))];
+	};
+
+	let path = Path::new(&icon);
+
+	match Icon::load(&workspace_dir.join(path)) {
+		Ok(loaded) => loaded
+			.validate()
+			.into_iter()
+			.map(|(severity, message)| Diagnostic::new(RULE, severity, message).with_path(path))
+			.collect(),
+		Err(err) => {
+			vec![Diagnostic::new(RULE, Severity::Error, format!("Icon cannot be read: {}", err)).with_path(path)]
+		}
+	}
+}
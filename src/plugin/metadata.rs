@@ -0,0 +1,279 @@
+use anyhow::Result;
+use colored::Colorize;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Serializer, Value};
+use std::{fs, path::Path};
+
+use super::META_FILE;
+use crate::{
+	ext::ResultExt,
+	util::get_json_formatter,
+	validation::{Diagnostic, Severity},
+};
+
+pub const RULE: &str = "metadata";
+
+/// JSON Schema of the `plugin.meta.json` file
+pub const SCHEMA: &str = include_str!("../../assets/schemas/plugin.meta.schema.json");
+
+pub const MAX_NAME_LENGTH: usize = 50;
+pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
+pub const MAX_TAGS: usize = 5;
+
+/// Tags that can be used to categorize the plugin
+pub const ALLOWED_TAGS: [&str; 16] = [
+	"animation",
+	"audio",
+	"building",
+	"debugging",
+	"effects",
+	"lighting",
+	"localization",
+	"modeling",
+	"physics",
+	"productivity",
+	"scripting",
+	"terrain",
+	"testing",
+	"ui",
+	"utility",
+	"vfx",
+];
+
+/// Prefix of icons referencing an uploaded image instead of a local file
+pub const ICON_ASSET_PREFIX: &str = "rbxassetid://";
+
+lazy_static! {
+	static ref EMAIL: Regex = Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap();
+	static ref URL: Regex = Regex::new(r"^https?://[^\s/]+\.[^\s]+$").unwrap();
+}
+
+/// Contents of the `plugin.meta.json` file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginMetadata {
+	#[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+	pub schema: Option<String>,
+
+	#[serde(default)]
+	pub name: String,
+	#[serde(default)]
+	pub description: String,
+	#[serde(default)]
+	pub version: String,
+	#[serde(default)]
+	pub tags: Vec<String>,
+
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub author: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub author_contact: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub icon: Option<String>,
+
+	/// Fields not known to Fluxo, kept as they are
+	#[serde(flatten)]
+	pub extra: Map<String, Value>,
+}
+
+impl PluginMetadata {
+	pub fn load(workspace_dir: &Path) -> Result<Self> {
+		let path = workspace_dir.join(META_FILE);
+
+		serde_json::from_str(&fs::read_to_string(&path)?)
+			.with_desc(|| format!("Failed to parse {}", path.display().to_string().bold()))
+	}
+
+	pub fn save(&self, workspace_dir: &Path) -> Result<()> {
+		let mut writer = Vec::new();
+		let mut serializer = Serializer::with_formatter(&mut writer, get_json_formatter());
+
+		self.serialize(&mut serializer)?;
+		fs::write(workspace_dir.join(META_FILE), &writer)?;
+
+		Ok(())
+	}
+
+	/// Normalize all problems that can be fixed automatically,
+	/// returns description of every applied fix
+	pub fn fix(&mut self) -> Vec<String> {
+		let mut fixes = Vec::new();
+
+		for (field, value) in [
+			("name", &mut self.name),
+			("description", &mut self.description),
+			("version", &mut self.version),
+		] {
+			if trim(value) {
+				fixes.push(format!("Trim whitespace in '{}'", field));
+			}
+		}
+
+		for (field, value) in [
+			("author", &mut self.author),
+			("authorContact", &mut self.author_contact),
+			("icon", &mut self.icon),
+		] {
+			if value.as_mut().is_some_and(trim) {
+				fixes.push(format!("Trim whitespace in '{}'", field));
+			}
+		}
+
+		if semver::Version::parse(&self.version).is_err() {
+			if let Some(version) = normalize_version(&self.version) {
+				fixes.push(format!("Change version '{}' to '{}'", self.version, version));
+				self.version = version;
+			}
+		}
+
+		let mut tags: Vec<String> = Vec::new();
+
+		for tag in &self.tags {
+			let normalized = tag.trim().to_lowercase();
+
+			if tags.contains(&normalized) {
+				fixes.push(format!("Remove duplicate tag '{}'", tag));
+				continue;
+			}
+
+			if *tag != normalized {
+				fixes.push(format!("Change tag '{}' to '{}'", tag, normalized));
+			}
+
+			tags.push(normalized);
+		}
+
+		self.tags = tags;
+
+		fixes
+	}
+
+	/// Check all fields, `workspace_dir` is used to resolve the icon path
+	pub fn validate(&self, workspace_dir: &Path) -> Vec<Diagnostic> {
+		let mut diagnostics = Vec::new();
+
+		let mut error = |message: String| diagnostics.push(Diagnostic::new(RULE, Severity::Error, message));
+
+		// Name
+		if self.name.trim().is_empty() {
+			error(String::from("Plugin name is required"));
+		} else if self.name.trim().chars().count() > MAX_NAME_LENGTH {
+			error(format!("Plugin name is longer than {} characters", MAX_NAME_LENGTH));
+		}
+
+		// Description
+		if self.description.trim().chars().count() > MAX_DESCRIPTION_LENGTH {
+			error(format!(
+				"Plugin description is longer than {} characters",
+				MAX_DESCRIPTION_LENGTH
+			));
+		}
+
+		// Version
+		if self.version.trim().is_empty() {
+			error(String::from("Plugin version is required"));
+		} else if semver::Version::parse(self.version.trim()).is_err() && normalize_version(&self.version).is_none() {
+			error(format!(
+				"Version '{}' is not a valid semantic version (e.g., 1.0.0)",
+				self.version
+			));
+		}
+
+		// Tags
+		if self.tags.len() > MAX_TAGS {
+			error(format!("Plugin can have at most {} tags", MAX_TAGS));
+		}
+
+		for tag in &self.tags {
+			if !ALLOWED_TAGS.contains(&tag.trim().to_lowercase().as_str()) {
+				error(format!(
+					"Unknown tag '{}', allowed tags: {}",
+					tag,
+					ALLOWED_TAGS.join(", ")
+				));
+			}
+		}
+
+		// Author contact
+		if let Some(contact) = self.author_contact.as_deref().map(str::trim) {
+			if !contact.is_empty() && !EMAIL.is_match(contact) && !URL.is_match(contact) {
+				error(format!(
+					"Author contact '{}' must be an email address or http(s) URL",
+					contact
+				));
+			}
+		}
+
+		// Icon
+		if let Some(icon) = self.icon.as_deref().map(str::trim) {
+			if let Some(id) = icon.strip_prefix(ICON_ASSET_PREFIX) {
+				if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+					error(format!("Icon '{}' is not a valid asset ID", icon));
+				}
+			} else if !icon.to_lowercase().ends_with(".png") {
+				error(format!(
+					"Icon '{}' must be a PNG file or {}<id> reference",
+					icon, ICON_ASSET_PREFIX
+				));
+			} else if !workspace_dir.join(icon).is_file() {
+				error(format!("Icon file '{}' does not exist", icon));
+			}
+		}
+
+		if self.description.trim().is_empty() {
+			diagnostics.push(Diagnostic::new(RULE, Severity::Warning, "Plugin description is empty"));
+		}
+
+		// Problems that `--fix` can take care of
+		for fix in self.clone().fix() {
+			diagnostics.push(Diagnostic::new(
+				RULE,
+				Severity::Warning,
+				format!("{} (fixable with --fix)", fix),
+			));
+		}
+
+		let path = Path::new(META_FILE);
+
+		diagnostics.into_iter().map(|d| d.with_path(path)).collect()
+	}
+}
+
+/// Pad partial versions like `1` or `1.0` and strip the `v` prefix,
+/// returns `None` if the result is still not a valid semantic version
+pub fn normalize_version(version: &str) -> Option<String> {
+	let version = version.trim();
+	let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+
+	let (core, suffix) = match version.find(['-', '+']) {
+		Some(index) => version.split_at(index),
+		None => (version, ""),
+	};
+
+	let mut parts: Vec<&str> = core.split('.').collect();
+
+	if parts.len() > 3 {
+		return None;
+	}
+
+	while parts.len() < 3 {
+		parts.push("0");
+	}
+
+	let normalized = format!("{}{}", parts.join("."), suffix);
+
+	semver::Version::parse(&normalized).ok().map(|_| normalized)
+}
+
+fn trim(value: &mut String) -> bool {
+	let trimmed = value.trim();
+
+	if trimmed.len() != value.len() {
+		*value = trimmed.to_owned();
+		true
+	} else {
+		false
+	}
+}
@@ -0,0 +1,13 @@
+pub mod changelog;
+pub mod channel;
+pub mod config;
+pub mod icon;
+pub mod metadata;
+pub mod team;
+pub mod version;
+
+/// Name of the Fluxo plugin configuration file
+pub const CONFIG_FILE: &str = "fluxo.config.json";
+
+/// Name of the plugin metadata file
+pub const META_FILE: &str = "plugin.meta.json";
@@ -0,0 +1,185 @@
+use anyhow::{bail, Result};
+use colored::Colorize;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::{env, fmt, path::Path};
+
+use crate::{git, util};
+
+/// Environment variable with the token required to act as another member
+pub const TOKEN_VAR: &str = "FLUXO_TEAM_TOKEN";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Permission {
+	Publish,
+	EditMetadata,
+}
+
+impl fmt::Display for Permission {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Permission::Publish => write!(f, "publish"),
+			Permission::EditMetadata => write!(f, "edit-metadata"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamConfig {
+	/// Permissions are enforced only when at least one member is declared
+	#[serde(default)]
+	pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Member {
+	pub name: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub email: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<String>,
+	#[serde(default)]
+	pub permissions: Vec<Permission>,
+	/// SHA-256 of the token that allows acting as this member with `--as`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub token_hash: Option<String>,
+}
+
+impl Member {
+	fn matches(&self, user: &User) -> bool {
+		let equal = |a: &str, b: &str| a.trim().eq_ignore_ascii_case(b.trim());
+
+		// Names are easy to spoof, so members with an email are identified only by it
+		match (&self.email, &user.email) {
+			(Some(email), Some(user_email)) => equal(email, user_email),
+			(Some(_), None) => false,
+			(None, _) => equal(&self.name, &user.name),
+		}
+	}
+}
+
+/// User running Fluxo
+#[derive(Debug, Clone, PartialEq)]
+pub struct User {
+	pub name: String,
+	pub email: Option<String>,
+}
+
+impl User {
+	/// Identify the user by Git `user.name` and `user.email`,
+	/// falling back to the system user name
+	pub fn current(dir: &Path) -> Result<Self> {
+		let name = git::config(dir, "user.name")?.unwrap_or_else(util::get_username);
+		let email = git::config(dir, "user.email")?;
+
+		Ok(Self { name, email })
+	}
+}
+
+impl fmt::Display for User {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match &self.email {
+			Some(email) => write!(f, "{} <{}>", self.name, email),
+			None => write!(f, "{}", self.name),
+		}
+	}
+}
+
+impl TeamConfig {
+	/// Returns the member matching `user`
+	pub fn find(&self, user: &User) -> Option<&Member> {
+		self.members.iter().find(|member| member.matches(user))
+	}
+
+	/// Fail if `user` is not a member with the `permission`
+	pub fn authorize(&self, user: &User, permission: Permission) -> Result<()> {
+		if self.members.is_empty() {
+			return Ok(());
+		}
+
+		let Some(member) = self.find(user) else {
+			bail!(
+				"{} is not a team member in fluxo.config.json and cannot {}",
+				user.to_string().bold(),
+				action(permission)
+			);
+		};
+
+		if !member.permissions.contains(&permission) {
+			bail!(
+				"Team member {} does not have the {} permission required to {}",
+				member.name.bold(),
+				permission.to_string().bold(),
+				action(permission)
+			);
+		}
+
+		debug!("{} is allowed to {}", member.name, action(permission));
+
+		Ok(())
+	}
+
+	/// Fail if the user is not allowed to use the `permission`, when `as_member`
+	/// is set, the token from `FLUXO_TEAM_TOKEN` is required to act as them
+	pub fn check(&self, dir: &Path, as_member: Option<&str>, permission: Permission) -> Result<()> {
+		if self.members.is_empty() {
+			return Ok(());
+		}
+
+		let Some(name) = as_member else {
+			return self.authorize(&User::current(dir)?, permission);
+		};
+
+		let member = self.members.iter().find(|member| {
+			member.name.eq_ignore_ascii_case(name.trim())
+				|| member
+					.email
+					.as_deref()
+					.is_some_and(|email| email.eq_ignore_ascii_case(name.trim()))
+		});
+
+		let Some(member) = member else {
+			bail!("Cannot act as {}, they are not a team member", name.bold());
+		};
+
+		let token = env::var(TOKEN_VAR).unwrap_or_default();
+
+		if token.trim().is_empty() {
+			bail!(
+				"Acting as another team member requires the {} environment variable",
+				TOKEN_VAR
+			);
+		}
+
+		match &member.token_hash {
+			Some(hash) if hash.trim().eq_ignore_ascii_case(&util::hash(token.trim())) => {}
+			Some(_) => bail!(
+				"Token from {} does not match the one of {}",
+				TOKEN_VAR,
+				member.name.bold()
+			),
+			None => bail!(
+				"Team member {} has no tokenHash in fluxo.config.json, so nobody can act as them",
+				member.name.bold()
+			),
+		}
+
+		self.authorize(
+			&User {
+				name: member.name.clone(),
+				email: member.email.clone(),
+			},
+			permission,
+		)
+	}
+}
+
+fn action(permission: Permission) -> &'static str {
+	match permission {
+		Permission::Publish => "publish the plugin",
+		Permission::EditMetadata => "edit plugin metadata",
+	}
+}
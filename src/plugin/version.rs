@@ -0,0 +1,82 @@
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use semver::{Prerelease, Version};
+
+/// Identifier used when bumping a stable version to a prerelease
+pub const DEFAULT_PRERELEASE: &str = "beta";
+
+/// Part of the semantic version to increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Bump {
+	Major,
+	Minor,
+	Patch,
+	Prerelease,
+}
+
+impl Bump {
+	/// Returns the version that follows `version`, prereleases
+	/// are released as they are when bumped to a matching level
+	pub fn apply(&self, version: &Version) -> Result<Version> {
+		self.apply_with(version, DEFAULT_PRERELEASE)
+	}
+
+	/// Same as [`Bump::apply`], starting new prereleases with `prerelease` identifier
+	pub fn apply_with(&self, version: &Version, prerelease: &str) -> Result<Version> {
+		let mut next = version.clone();
+		next.build = semver::BuildMetadata::EMPTY;
+
+		let is_prerelease = !version.pre.is_empty();
+
+		match self {
+			Bump::Major => {
+				if !is_prerelease || version.minor != 0 || version.patch != 0 {
+					next.major += 1;
+					next.minor = 0;
+					next.patch = 0;
+				}
+			}
+			Bump::Minor => {
+				if !is_prerelease || version.patch != 0 {
+					next.minor += 1;
+					next.patch = 0;
+				}
+			}
+			Bump::Patch => {
+				if !is_prerelease {
+					next.patch += 1;
+				}
+			}
+			Bump::Prerelease => {
+				if is_prerelease {
+					next.pre = next_prerelease(&version.pre)?;
+				} else {
+					next.patch += 1;
+					next.pre = Prerelease::new(&format!("{}.1", prerelease))?;
+				}
+
+				return Ok(next);
+			}
+		}
+
+		next.pre = Prerelease::EMPTY;
+
+		Ok(next)
+	}
+}
+
+/// Increment the last numeric identifier of `pre`,
+/// or append `.1` if there is none (`beta` -> `beta.1`)
+fn next_prerelease(pre: &Prerelease) -> Result<Prerelease> {
+	let mut identifiers: Vec<String> = pre.as_str().split('.').map(str::to_owned).collect();
+
+	match identifiers.last().and_then(|last| last.parse::<u64>().ok()) {
+		Some(number) => *identifiers.last_mut().unwrap() = (number + 1).to_string(),
+		None => identifiers.push(String::from("1")),
+	}
+
+	match Prerelease::new(&identifiers.join(".")) {
+		Ok(pre) => Ok(pre),
+		Err(err) => bail!("Failed to bump prerelease '{}': {}", pre, err),
+	}
+}
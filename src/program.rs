@@ -94,6 +94,20 @@ impl Program {
 		}
 	}
 
+	/// Like `output` but always captures stdout and stderr, even when logging is disabled
+	pub fn output_captured(&mut self) -> Result<Option<Output>> {
+		let result = self
+			.get_command()
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.output();
+
+		match result {
+			Ok(output) => Ok(Some(output)),
+			Err(err) => self.handle_error(err),
+		}
+	}
+
 	fn get_command(&self) -> Command {
 		if self.program == ProgramName::Argon {
 			let mut command = Command::new(env::current_exe().unwrap_or(PathBuf::from("argon")));
@@ -9,7 +9,6 @@ use std::{
 	path::{Path, PathBuf},
 };
 use walkdir;
-use chrono;
 
 use crate::{
 	config::Config,
@@ -255,58 +254,6 @@ impl Project {
 			"place_ids": self.place_ids
 		})
 	}
-	
-	pub fn validate(&self) -> serde_json::Value {
-		let mut issues = Vec::new();
-		let mut warnings = Vec::new();
-		let mut suggestions = Vec::new();
-		
-		// Basic validation
-		if self.name.is_empty() {
-			issues.push("Project name is empty".to_string());
-		}
-		
-		// Check for plugin metadata file
-		let plugin_meta_path = self.workspace_dir.join("plugin.meta.json");
-		if !plugin_meta_path.exists() {
-			warnings.push("Missing plugin.meta.json file".to_string());
-		}
-		
-		// Check for fluxo config
-		let fluxo_config_path = self.workspace_dir.join("fluxo.config.json");
-		if !fluxo_config_path.exists() {
-			warnings.push("Missing fluxo.config.json file".to_string());
-		}
-		
-		// Check source files
-		if let Ok(files) = self.get_source_files() {
-			if files.is_empty() {
-				issues.push("No source files found".to_string());
-			}
-			
-			// Check for banned APIs
-			for (file_path, content) in &files {
-				if content.contains("loadstring") {
-					issues.push(format!("Banned API 'loadstring' found in {}", file_path));
-				}
-				if content.contains("getfenv") {
-					issues.push(format!("Banned API 'getfenv' found in {}", file_path));
-				}
-			}
-		}
-		
-		// Suggestions
-		suggestions.push("Consider adding documentation".to_string());
-		suggestions.push("Use consistent naming conventions".to_string());
-		
-		serde_json::json!({
-			"success": issues.is_empty(),
-			"issues": issues,
-			"warnings": warnings,
-			"suggestions": suggestions,
-			"timestamp": chrono::Utc::now().to_rfc3339()
-		})
-	}
 
 	// ...existing methods...
 }
@@ -0,0 +1,222 @@
+use anyhow::{anyhow, bail, Result};
+use log::{debug, trace};
+use reqwest::{
+	blocking::{Client, RequestBuilder, Response},
+	header::CONTENT_TYPE,
+	Method,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{env, thread, time::Duration};
+use uuid::Uuid;
+
+use crate::{ext::ResultExt, plugin::config::Creator};
+
+/// Environment variable with the Open Cloud API key
+pub const API_KEY_VAR: &str = "FLUXO_API_KEY";
+
+pub const DEFAULT_API_URL: &str = "https://apis.roblox.com";
+
+const MODEL_CONTENT_TYPE: &str = "model/x-rbxm";
+
+/// Model to upload as a new asset or a new version of an existing one
+pub struct Upload<'a> {
+	/// Existing asset to update, a new asset is created when `None`
+	pub asset_id: Option<u64>,
+	/// Required when creating a new asset
+	pub creator: Option<Creator>,
+	pub name: &'a str,
+	pub description: &'a str,
+	/// Plugin serialized in the binary model format
+	pub data: &'a [u8],
+}
+
+/// Asset created or updated by the upload
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Asset {
+	pub id: u64,
+	/// Revision of the asset, if reported by the API
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub version: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Operation {
+	path: String,
+	#[serde(default)]
+	done: bool,
+	response: Option<Value>,
+	error: Option<Value>,
+}
+
+/// Headless publishing backend using the Open Cloud assets API
+pub struct OpenCloud {
+	client: Client,
+	api_url: String,
+	api_key: String,
+	poll_interval: Duration,
+	max_polls: usize,
+}
+
+impl OpenCloud {
+	pub fn new(api_key: &str) -> Self {
+		Self {
+			client: Client::new(),
+			api_url: DEFAULT_API_URL.to_owned(),
+			api_key: api_key.to_owned(),
+			poll_interval: Duration::from_secs(1),
+			max_polls: 60,
+		}
+	}
+
+	/// Create the backend with the API key read from the `FLUXO_API_KEY` variable
+	pub fn from_env() -> Result<Self> {
+		match env::var(API_KEY_VAR) {
+			Ok(key) if !key.trim().is_empty() => Ok(Self::new(key.trim())),
+			_ => bail!(
+				"Open Cloud API key is missing, set the {} environment variable",
+				API_KEY_VAR
+			),
+		}
+	}
+
+	pub fn with_api_url(mut self, api_url: &str) -> Self {
+		self.api_url = api_url.trim_end_matches('/').to_owned();
+		self
+	}
+
+	pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+		self.poll_interval = poll_interval;
+		self
+	}
+
+	/// Upload the model and wait until the asset is processed
+	pub fn upload(&self, upload: &Upload) -> Result<Asset> {
+		let (method, url, request) = match upload.asset_id {
+			Some(asset_id) => (
+				Method::PATCH,
+				format!("{}/assets/v1/assets/{}", self.api_url, asset_id),
+				json!({
+					"assetId": asset_id.to_string(),
+					"assetType": "Model",
+				}),
+			),
+			None => {
+				let creator = match upload.creator {
+					Some(Creator::UserId(id)) => json!({ "userId": id.to_string() }),
+					Some(Creator::GroupId(id)) => json!({ "groupId": id.to_string() }),
+					None => bail!("Creating a new asset requires publishing.creator in fluxo.config.json"),
+				};
+
+				(
+					Method::POST,
+					format!("{}/assets/v1/assets", self.api_url),
+					json!({
+						"assetType": "Model",
+						"displayName": upload.name,
+						"description": upload.description,
+						"creationContext": { "creator": creator },
+					}),
+				)
+			}
+		};
+
+		// Random boundary so it cannot appear in the model data
+		let boundary = Uuid::new_v4().simple().to_string();
+
+		let response = self
+			.request(method, &url)
+			.header(CONTENT_TYPE, format!("multipart/form-data; boundary={}", boundary))
+			.body(multipart(&boundary, &request, upload.data))
+			.send()
+			.with_desc(|| format!("Failed to reach Open Cloud at {}", self.api_url))?;
+
+		let mut operation: Operation = parse(response)?;
+
+		debug!("Upload started, operation: {}", operation.path);
+
+		for _ in 0..self.max_polls {
+			if operation.done {
+				return asset(operation);
+			}
+
+			thread::sleep(self.poll_interval);
+
+			let id = operation.path.rsplit('/').next().unwrap_or_default();
+			let url = format!("{}/assets/v1/operations/{}", self.api_url, id);
+
+			trace!("Polling operation {}", id);
+
+			operation = parse(self.request(Method::GET, &url).send()?)?;
+		}
+
+		if operation.done {
+			return asset(operation);
+		}
+
+		bail!("Asset was not processed in time, operation: {}", operation.path)
+	}
+
+	fn request(&self, method: Method, url: &str) -> RequestBuilder {
+		self.client.request(method, url).header("x-api-key", &self.api_key)
+	}
+}
+
+fn parse(response: Response) -> Result<Operation> {
+	let status = response.status();
+	let body = response.text()?;
+
+	if !status.is_success() {
+		let message = serde_json::from_str::<Value>(&body)
+			.ok()
+			.and_then(|body| body["message"].as_str().map(str::to_owned))
+			.unwrap_or(body);
+
+		bail!("Open Cloud request failed with {}: {}", status, message);
+	}
+
+	serde_json::from_str(&body).desc("Open Cloud returned an invalid operation")
+}
+
+fn asset(operation: Operation) -> Result<Asset> {
+	if let Some(error) = operation.error {
+		bail!(
+			"Asset processing failed: {}",
+			error["message"].as_str().unwrap_or("unknown error")
+		);
+	}
+
+	let response = operation
+		.response
+		.ok_or_else(|| anyhow!("Operation {} finished without a response", operation.path))?;
+
+	// Open Cloud returns 64 bit identifiers as strings
+	let number = |value: &Value| match value {
+		Value::String(string) => string.parse().ok(),
+		value => value.as_u64(),
+	};
+
+	let id = number(&response["assetId"]).ok_or_else(|| anyhow!("Operation response has no asset ID"))?;
+
+	Ok(Asset {
+		id,
+		version: number(&response["revisionId"]),
+	})
+}
+
+/// Build multipart form with the JSON `request` and the model file
+fn multipart(boundary: &str, request: &Value, data: &[u8]) -> Vec<u8> {
+	let mut body = Vec::new();
+
+	body.extend(format!("--{}\r\n", boundary).as_bytes());
+	body.extend(b"Content-Disposition: form-data; name=\"request\"\r\n\r\n");
+	body.extend(request.to_string().as_bytes());
+	body.extend(format!("\r\n--{}\r\n", boundary).as_bytes());
+	body.extend(b"Content-Disposition: form-data; name=\"fileContent\"; filename=\"plugin.rbxm\"\r\n");
+	body.extend(format!("Content-Type: {}\r\n\r\n", MODEL_CONTENT_TYPE).as_bytes());
+	body.extend(data);
+	body.extend(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+	body
+}
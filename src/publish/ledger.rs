@@ -0,0 +1,209 @@
+use anyhow::Result;
+use chrono::{SecondsFormat, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use serde_json::Serializer;
+use std::{
+	collections::BTreeMap,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use super::{cloud::Asset, Artifact};
+use crate::{
+	ext::ResultExt,
+	plugin::metadata::PluginMetadata,
+	util::{self, get_json_formatter},
+};
+
+/// Path of the publish history, relative to the workspace
+pub const LEDGER_FILE: &str = ".fluxo/publishes.json";
+
+/// Directory where published plugins are kept by their hash, relative to the workspace
+pub const ARTIFACTS_DIR: &str = ".fluxo/artifacts";
+
+/// Record of a single successful publish
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+	pub version: String,
+	/// Release channel the plugin was published to
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub channel: Option<String>,
+	/// RFC 3339 time of the publish, in UTC
+	pub timestamp: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub notes: Option<String>,
+	/// Git commit the plugin was published from
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub commit: Option<String>,
+	/// Content hash of the built plugin
+	pub hash: String,
+	/// Asset the plugin was uploaded to, when published through Open Cloud
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub asset: Option<Asset>,
+	pub metadata: PluginMetadata,
+	/// Hashes of all source files, keyed by path relative to the workspace
+	pub files: BTreeMap<String, String>,
+}
+
+impl Entry {
+	pub fn new(artifact: &Artifact, metadata: &PluginMetadata, notes: Option<String>, commit: Option<String>) -> Self {
+		Self {
+			version: metadata.version.clone(),
+			channel: None,
+			timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+			notes,
+			commit,
+			hash: artifact.hash.clone(),
+			asset: None,
+			metadata: metadata.clone(),
+			files: artifact.files.clone(),
+		}
+	}
+
+	/// Returns a new entry for the same built plugin published again as `metadata` to `channel`
+	pub fn promote(&self, metadata: &PluginMetadata, channel: &str) -> Self {
+		Self {
+			version: metadata.version.clone(),
+			channel: Some(channel.to_owned()),
+			timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+			asset: None,
+			metadata: metadata.clone(),
+			..self.clone()
+		}
+	}
+
+	/// Returns sorted changes of `files` since this publish
+	pub fn diff(&self, files: &BTreeMap<String, String>) -> Vec<(String, FileChange)> {
+		diff_files(&self.files, files)
+	}
+}
+
+/// Returns sorted changes between `old` and `new` file hashes
+pub fn diff_files(old: &BTreeMap<String, String>, new: &BTreeMap<String, String>) -> Vec<(String, FileChange)> {
+	let mut changes = Vec::new();
+
+	for (path, hash) in new {
+		match old.get(path) {
+			None => changes.push((path.clone(), FileChange::Added)),
+			Some(old) if old != hash => changes.push((path.clone(), FileChange::Modified)),
+			_ => {}
+		}
+	}
+
+	for path in old.keys() {
+		if !new.contains_key(path) {
+			changes.push((path.clone(), FileChange::Removed));
+		}
+	}
+
+	changes.sort();
+	changes
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FileChange {
+	Added,
+	Modified,
+	Removed,
+}
+
+impl FileChange {
+	/// Short marker used when listing changes
+	pub fn marker(&self) -> String {
+		match self {
+			FileChange::Added => "+".green().to_string(),
+			FileChange::Modified => "~".yellow().to_string(),
+			FileChange::Removed => "-".red().to_string(),
+		}
+	}
+}
+
+/// History of all publishes of the plugin, oldest first
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+	publishes: Vec<Entry>,
+
+	#[serde(skip)]
+	path: PathBuf,
+}
+
+impl Ledger {
+	/// Load the ledger of the given workspace, empty if it does not exist yet
+	pub fn load(workspace_dir: &Path) -> Result<Self> {
+		let path = workspace_dir.join(LEDGER_FILE);
+
+		if !path.exists() {
+			return Ok(Self {
+				path,
+				..Default::default()
+			});
+		}
+
+		let ledger: Ledger = serde_json::from_str(&fs::read_to_string(&path)?)
+			.with_desc(|| format!("Failed to parse {}", path.display()))?;
+
+		Ok(Self { path, ..ledger })
+	}
+
+	pub fn entries(&self) -> &[Entry] {
+		&self.publishes
+	}
+
+	/// Returns the most recent entry
+	pub fn last(&self) -> Option<&Entry> {
+		self.publishes.last()
+	}
+
+	/// Returns the most recent publish of `version`
+	pub fn find(&self, version: &str) -> Option<&Entry> {
+		let version = version.trim().trim_start_matches(['v', 'V']);
+
+		self.publishes.iter().rev().find(|entry| entry.version == version)
+	}
+
+	/// Returns the most recent publish to `channel`
+	pub fn last_in(&self, channel: &str) -> Option<&Entry> {
+		self.publishes
+			.iter()
+			.rev()
+			.find(|entry| entry.channel.as_deref() == Some(channel))
+	}
+
+	pub fn push(&mut self, entry: Entry) {
+		self.publishes.push(entry);
+	}
+
+	pub fn save(&self) -> Result<()> {
+		let mut writer = Vec::new();
+		let mut serializer = Serializer::with_formatter(&mut writer, get_json_formatter());
+
+		self.serialize(&mut serializer)?;
+
+		fs::create_dir_all(self.path.parent().unwrap())?;
+		fs::write(&self.path, &writer).with_desc(|| format!("Failed to write {}", self.path.display()))
+	}
+}
+
+/// Keep the built plugin so it can be published again without rebuilding
+pub fn store_artifact(workspace_dir: &Path, artifact: &Artifact) -> Result<()> {
+	let dir = workspace_dir.join(ARTIFACTS_DIR);
+
+	fs::create_dir_all(&dir)?;
+	fs::write(dir.join(format!("{}.rbxm", artifact.hash)), &artifact.data)
+		.with_desc(|| format!("Failed to store artifact {}", artifact.hash))
+}
+
+/// Returns the stored plugin with the given `hash`, `None` if it
+/// was not stored or its contents no longer match the hash
+pub fn load_artifact(workspace_dir: &Path, hash: &str) -> Option<Vec<u8>> {
+	let path = workspace_dir.join(ARTIFACTS_DIR).join(format!("{}.rbxm", hash));
+	let data = fs::read(path).ok()?;
+
+	if util::hash(&data) == hash {
+		Some(data)
+	} else {
+		None
+	}
+}
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use std::{collections::BTreeMap, path::Path};
+
+use crate::{core::Core, ext::ResultExt, plugin::META_FILE, project::Project, util};
+
+pub mod cloud;
+pub mod ledger;
+pub mod notes;
+pub mod package;
+pub mod payload;
+
+/// Plugin built for publishing, with hashes of all files it was built from
+pub struct Artifact {
+	/// Plugin serialized in the binary model format
+	pub data: Vec<u8>,
+	/// Hash of the `data`
+	pub hash: String,
+	/// Hashes of source files, keyed by path relative to the workspace
+	pub files: BTreeMap<String, String>,
+	/// Sizes of source files in bytes, keyed the same way as `files`
+	pub sizes: BTreeMap<String, usize>,
+}
+
+impl Artifact {
+	/// Build the project at `project_path`
+	pub fn build(project_path: &Path) -> Result<Self> {
+		let project = Project::load(project_path)?;
+		let workspace_dir = project.workspace_dir.clone();
+
+		let core = Core::new(project, false)?;
+
+		let mut data = Vec::new();
+		core.write(&mut data, false).desc("Failed to build plugin")?;
+
+		let mut paths = core.source_files();
+		paths.push(workspace_dir.join(META_FILE));
+
+		let mut files = BTreeMap::new();
+		let mut sizes = BTreeMap::new();
+
+		for path in paths {
+			// Files can be referenced by instances but not exist, like optional `.meta.json`
+			let Ok(contents) = std::fs::read(&path) else {
+				continue;
+			};
+
+			let path = relative_path(&path, &workspace_dir);
+
+			sizes.insert(path.clone(), contents.len());
+			files.insert(path, util::hash(contents));
+		}
+
+		Ok(Self {
+			hash: util::hash(&data),
+			data,
+			files,
+			sizes,
+		})
+	}
+}
+
+/// Returns `path` relative to `workspace_dir`, always with forward slashes
+pub fn relative_path(path: &Path, workspace_dir: &Path) -> String {
+	path.strip_prefix(workspace_dir)
+		.unwrap_or(path)
+		.to_string_lossy()
+		.replace('\\', "/")
+}
@@ -0,0 +1,136 @@
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+use crate::git::{self, Commit};
+
+lazy_static! {
+	static ref CONVENTIONAL: Regex = Regex::new(r"^(\w+)(?:\(([^)]*)\))?(!)?:\s*(.+)$").unwrap();
+}
+
+/// Commit types in the order they are listed, with their section titles
+const SECTIONS: [(&str, &str); 11] = [
+	("feat", "Features"),
+	("fix", "Bug Fixes"),
+	("perf", "Performance"),
+	("refactor", "Refactoring"),
+	("revert", "Reverts"),
+	("docs", "Documentation"),
+	("style", "Style"),
+	("test", "Tests"),
+	("build", "Build"),
+	("ci", "Continuous Integration"),
+	("chore", "Chores"),
+];
+
+/// Commit parsed according to the Conventional Commits specification
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+	/// Lowercase commit type, `None` if the commit is not conventional
+	pub kind: Option<String>,
+	pub scope: Option<String>,
+	pub description: String,
+	pub breaking: bool,
+	pub hash: String,
+}
+
+impl Change {
+	pub fn parse(commit: &Commit) -> Self {
+		let breaking_footer = commit
+			.body
+			.lines()
+			.any(|line| line.starts_with("BREAKING CHANGE:") || line.starts_with("BREAKING-CHANGE:"));
+
+		let Some(captures) = CONVENTIONAL.captures(&commit.subject) else {
+			return Self {
+				kind: None,
+				scope: None,
+				description: commit.subject.clone(),
+				breaking: breaking_footer,
+				hash: commit.hash.clone(),
+			};
+		};
+
+		Self {
+			kind: Some(captures[1].to_lowercase()),
+			scope: captures
+				.get(2)
+				.map(|scope| scope.as_str().trim().to_owned())
+				.filter(|scope| !scope.is_empty()),
+			description: captures[4].trim().to_owned(),
+			breaking: captures.get(3).is_some() || breaking_footer,
+			hash: commit.hash.clone(),
+		}
+	}
+
+	fn entry(&self) -> String {
+		match &self.scope {
+			Some(scope) => format!("- **{}:** {} ({})", scope, self.description, self.hash),
+			None => format!("- {} ({})", self.description, self.hash),
+		}
+	}
+}
+
+/// Returns release notes with `commits` grouped by type, breaking changes first
+/// and non-conventional commits last, `None` if there are no commits
+pub fn generate(commits: &[Commit]) -> Option<String> {
+	let changes: Vec<Change> = commits.iter().map(Change::parse).collect();
+
+	let mut sections: Vec<(&str, Vec<&Change>)> = vec![("Breaking Changes", vec![])];
+
+	sections.extend(SECTIONS.iter().map(|(_, title)| (*title, vec![])));
+	sections.push(("Other Changes", vec![]));
+
+	for change in &changes {
+		let index = if change.breaking {
+			0
+		} else {
+			change
+				.kind
+				.as_deref()
+				.and_then(|kind| SECTIONS.iter().position(|(name, _)| *name == kind))
+				.map(|index| index + 1)
+				.unwrap_or(sections.len() - 1)
+		};
+
+		sections[index].1.push(change);
+	}
+
+	let notes: Vec<String> = sections
+		.into_iter()
+		.filter(|(_, changes)| !changes.is_empty())
+		.map(|(title, changes)| {
+			let entries: Vec<String> = changes.iter().map(|change| change.entry()).collect();
+			format!("### {}\n\n{}", title, entries.join("\n"))
+		})
+		.collect();
+
+	if notes.is_empty() {
+		None
+	} else {
+		Some(notes.join("\n\n"))
+	}
+}
+
+/// Returns the revision release notes start from: the commit of the last publish
+/// or the last tag, whichever is more recent, `None` to include the whole history
+pub fn base(dir: &Path, published: Option<&str>) -> Result<Option<String>> {
+	let published = match published {
+		Some(commit) if git::has_commit(dir, commit)? => Some(commit.to_owned()),
+		_ => None,
+	};
+
+	let base = match (published, git::last_tag(dir)?) {
+		(Some(commit), Some(tag)) => {
+			if git::is_ancestor(dir, &commit, &tag)? {
+				Some(tag)
+			} else {
+				Some(commit)
+			}
+		}
+		(commit, tag) => commit.or(tag),
+	};
+
+	Ok(base)
+}
@@ -0,0 +1,146 @@
+use anyhow::{bail, Result};
+use chrono::{SecondsFormat, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::BTreeMap,
+	fs::{self, File},
+	io::{Read, Write},
+	path::{Component, Path},
+};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use super::Artifact;
+use crate::{
+	ext::{PathExt, ResultExt},
+	plugin::{
+		changelog::{self, CHANGELOG_FILE},
+		icon,
+		metadata::PluginMetadata,
+		META_FILE,
+	},
+	util,
+};
+
+/// Name of the file with checksums of all other files in the bundle
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Name of the built plugin in the bundle
+pub const MODEL_FILE: &str = "plugin.rbxm";
+
+/// Description of the bundle contents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+	pub name: String,
+	pub version: String,
+	/// RFC 3339 time of packaging, in UTC
+	pub created: String,
+	/// SHA-256 checksums of all files in the bundle, except the manifest itself
+	pub files: BTreeMap<String, String>,
+}
+
+/// Build the project at `project_path` and write the bundle to `output`
+pub fn create(project_path: &Path, output: &Path) -> Result<Manifest> {
+	let workspace_dir = project_path.get_parent();
+
+	let metadata = PluginMetadata::load(workspace_dir)?;
+	let artifact = Artifact::build(project_path)?;
+
+	let mut files: Vec<(String, Vec<u8>)> = vec![
+		(MODEL_FILE.to_owned(), artifact.data),
+		(META_FILE.to_owned(), fs::read(workspace_dir.join(META_FILE))?),
+	];
+
+	// Icons can also be asset IDs, only local files are bundled
+	if let Some(icon) = icon::locate(workspace_dir, Some(&metadata)) {
+		let name = icon.replace('\\', "/");
+
+		// Entry names must stay inside the bundle when it is extracted
+		let inside = Path::new(&name)
+			.components()
+			.all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+		if !inside {
+			bail!("Icon {} must be a path inside the project to be bundled", icon);
+		}
+
+		files.push((name, fs::read(workspace_dir.join(&icon))?));
+	}
+
+	let changelog_path = workspace_dir.join(CHANGELOG_FILE);
+
+	if changelog_path.exists() {
+		match changelog::excerpt(&fs::read_to_string(&changelog_path)?, &metadata.version) {
+			Some(excerpt) => files.push((CHANGELOG_FILE.to_owned(), excerpt.into_bytes())),
+			None => warn!("{} has no section for version {}", CHANGELOG_FILE, metadata.version),
+		}
+	}
+
+	let manifest = Manifest {
+		name: metadata.name,
+		version: metadata.version,
+		created: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+		files: files
+			.iter()
+			.map(|(name, contents)| (name.clone(), util::hash(contents)))
+			.collect(),
+	};
+
+	files.push((MANIFEST_FILE.to_owned(), serde_json::to_vec_pretty(&manifest)?));
+
+	let file = File::create(output).with_desc(|| format!("Failed to create {}", output.display()))?;
+	let mut zip = ZipWriter::new(file);
+
+	let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+	for (name, contents) in files {
+		zip.start_file(name, options)?;
+		zip.write_all(&contents)?;
+	}
+
+	zip.finish()?;
+
+	Ok(manifest)
+}
+
+/// Check every file in the bundle at `path` against its manifest,
+/// returns the manifest and description of every problem found
+pub fn verify(path: &Path) -> Result<(Manifest, Vec<String>)> {
+	let file = File::open(path).with_desc(|| format!("Failed to open {}", path.display()))?;
+	let mut zip = ZipArchive::new(file).with_desc(|| format!("{} is not a valid zip file", path.display()))?;
+
+	let manifest: Manifest = match zip.by_name(MANIFEST_FILE) {
+		Ok(mut file) => {
+			let mut contents = String::new();
+			file.read_to_string(&mut contents)?;
+
+			serde_json::from_str(&contents).desc("Bundle manifest is corrupted")?
+		}
+		Err(_) => bail!("Bundle has no {}", MANIFEST_FILE),
+	};
+
+	let mut problems = Vec::new();
+
+	for (name, checksum) in &manifest.files {
+		let Ok(mut file) = zip.by_name(name) else {
+			problems.push(format!("File {} is missing", name));
+			continue;
+		};
+
+		let mut contents = Vec::new();
+		file.read_to_end(&mut contents)?;
+
+		if util::hash(&contents) != *checksum {
+			problems.push(format!("Checksum of {} does not match", name));
+		}
+	}
+
+	for name in zip.file_names() {
+		if name != MANIFEST_FILE && !manifest.files.contains_key(name) {
+			problems.push(format!("File {} is not listed in the manifest", name));
+		}
+	}
+
+	Ok((manifest, problems))
+}
@@ -0,0 +1,191 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Serializer, Value};
+use std::{
+	collections::BTreeMap,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use super::{ledger::Entry, package::MODEL_FILE, Artifact};
+use crate::{ext::ResultExt, plugin::metadata::PluginMetadata, util::get_json_formatter};
+
+/// Directory where dry runs are written, relative to the workspace
+pub const DRY_RUN_DIR: &str = ".fluxo/dry-run";
+
+pub const PAYLOAD_FILE: &str = "payload.json";
+
+/// Source file the plugin was built from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceFile {
+	pub hash: String,
+	pub size: usize,
+}
+
+/// Everything that would be sent by a publish, without the model itself
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Payload {
+	pub metadata: PluginMetadata,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub notes: Option<String>,
+	/// Release channel the plugin is published to
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub channel: Option<String>,
+	/// Content hash of the built plugin
+	pub hash: String,
+	/// Size of the built plugin in bytes
+	pub size: usize,
+	/// Source files keyed by path relative to the workspace
+	pub files: BTreeMap<String, SourceFile>,
+}
+
+impl Payload {
+	pub fn new(artifact: &Artifact, metadata: &PluginMetadata, notes: Option<String>) -> Self {
+		let files = artifact
+			.files
+			.iter()
+			.map(|(path, hash)| {
+				let file = SourceFile {
+					hash: hash.clone(),
+					size: artifact.sizes.get(path).copied().unwrap_or_default(),
+				};
+
+				(path.clone(), file)
+			})
+			.collect();
+
+		Self {
+			metadata: metadata.clone(),
+			notes,
+			channel: None,
+			hash: artifact.hash.clone(),
+			size: artifact.data.len(),
+			files,
+		}
+	}
+
+	/// Load a payload from its file or from the directory of a dry run
+	pub fn load(path: &Path) -> Result<Self> {
+		let path = if path.is_dir() {
+			path.join(PAYLOAD_FILE)
+		} else {
+			path.to_owned()
+		};
+
+		let contents = fs::read_to_string(&path).with_desc(|| format!("Failed to read {}", path.display()))?;
+
+		serde_json::from_str(&contents).with_desc(|| format!("Failed to parse {}", path.display()))
+	}
+
+	/// Write the payload and the built plugin `data` to `dir`, returns path of the payload
+	pub fn save(&self, dir: &Path, data: &[u8]) -> Result<PathBuf> {
+		let mut writer = Vec::new();
+		let mut serializer = Serializer::with_formatter(&mut writer, get_json_formatter());
+
+		self.serialize(&mut serializer)?;
+
+		let path = dir.join(PAYLOAD_FILE);
+
+		fs::create_dir_all(dir)?;
+		fs::write(&path, &writer).with_desc(|| format!("Failed to write {}", path.display()))?;
+		fs::write(dir.join(MODEL_FILE), data).with_desc(|| format!("Failed to write {}", MODEL_FILE))?;
+
+		Ok(path)
+	}
+
+	/// Body of the publish request sent to Studio, the server
+	/// refuses it unless the synced tree builds to the same `hash`
+	pub fn request(&self) -> Value {
+		let mut request = json!({
+			"metadata": self.metadata,
+			"notes": self.notes,
+			"hash": self.hash,
+		});
+
+		if let Some(channel) = &self.channel {
+			request["channel"] = json!(channel);
+		}
+
+		request
+	}
+
+	/// Returns hashes of the source files, as stored in the ledger
+	pub fn hashes(&self) -> BTreeMap<String, String> {
+		self.files
+			.iter()
+			.map(|(path, file)| (path.clone(), file.hash.clone()))
+			.collect()
+	}
+}
+
+/// Payload of a previous publish, the ledger does not record sizes
+impl From<&Entry> for Payload {
+	fn from(entry: &Entry) -> Self {
+		let files = entry
+			.files
+			.iter()
+			.map(|(path, hash)| {
+				let file = SourceFile {
+					hash: hash.clone(),
+					size: 0,
+				};
+
+				(path.clone(), file)
+			})
+			.collect();
+
+		Self {
+			metadata: entry.metadata.clone(),
+			notes: entry.notes.clone(),
+			channel: entry.channel.clone(),
+			hash: entry.hash.clone(),
+			size: 0,
+			files,
+		}
+	}
+}
+
+/// Field of the plugin metadata that differs between two publishes
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataChange {
+	pub field: String,
+	pub old: Option<Value>,
+	pub new: Option<Value>,
+}
+
+/// Returns changed metadata fields, sorted by name
+pub fn diff_metadata(old: &PluginMetadata, new: &PluginMetadata) -> Vec<MetadataChange> {
+	let fields = |metadata: &PluginMetadata| match serde_json::to_value(metadata) {
+		Ok(Value::Object(map)) => map.into_iter().collect::<BTreeMap<_, _>>(),
+		_ => BTreeMap::new(),
+	};
+
+	let old = fields(old);
+	let mut new = fields(new);
+
+	let mut changes = Vec::new();
+
+	for (field, value) in old {
+		let new = new.remove(&field);
+
+		if new.as_ref() != Some(&value) {
+			changes.push(MetadataChange {
+				field,
+				old: Some(value),
+				new,
+			});
+		}
+	}
+
+	for (field, value) in new {
+		changes.push(MetadataChange {
+			field,
+			old: None,
+			new: Some(value),
+		});
+	}
+
+	changes.sort_by(|a, b| a.field.cmp(&b.field));
+	changes
+}
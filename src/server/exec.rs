@@ -1,22 +1,37 @@
 use actix_msgpack::MsgPack;
-use actix_web::{post, web::Data, HttpResponse, Responder};
+use actix_web::{post, web::Data, HttpRequest, HttpResponse, Responder};
 use log::{error, trace};
 use serde::Deserialize;
 use std::sync::Arc;
 
-use crate::{core::Core, server, studio};
+use crate::{
+	core::Core,
+	server::{self, Token},
+	studio,
+};
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 struct Request {
 	code: String,
 	focus: bool,
+	#[serde(default)]
+	token: Option<String>,
 }
 
 #[post("/exec")]
-async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responder {
+async fn main(
+	http_request: HttpRequest,
+	request: MsgPack<Request>,
+	core: Data<Arc<Core>>,
+	token: Data<Token>,
+) -> impl Responder {
 	trace!("Received request: exec");
 
+	if let Some(response) = token.check(&http_request, request.token.as_deref()) {
+		return response;
+	}
+
 	let queue = core.queue();
 
 	let pushed = queue.push(
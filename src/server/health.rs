@@ -0,0 +1,13 @@
+use actix_web::{get, HttpResponse, Responder};
+use log::trace;
+use serde_json::json;
+
+#[get("/health")]
+async fn main() -> impl Responder {
+	trace!("Received request: health");
+
+	HttpResponse::Ok().json(json!({
+		"status": "ok",
+		"service": "fluxo-cli"
+	}))
+}
@@ -1,43 +1,57 @@
 use actix_msgpack::MsgPackConfig;
 use actix_web::{
 	web::{self, Data},
-	App, HttpServer, Responder,
+	App, HttpRequest, HttpResponse, HttpServer, Responder,
 };
 use derive_from_one::FromOne;
 use serde::{Deserialize, Serialize};
 use std::{io::Result, net::TcpListener, sync::Arc};
+use uuid::Uuid;
 
 use crate::{
 	constants::MAX_PAYLOAD_SIZE,
-	core::{changes::Changes, Core},
+	core::{changes::Changes, snapshot::AddedSnapshot, Core},
 	project::ProjectDetails,
+	validation::report::Report,
 };
 
 mod details;
 mod exec;
+mod health;
 mod home;
 mod open;
+mod publish;
 mod read;
 mod snapshot;
+mod socket;
 mod stop;
 mod subscribe;
+mod sync;
 mod unsubscribe;
+mod validate;
 mod write;
 
-pub mod http;
+/// Header carrying the session token, mutating endpoints
+/// also accept it in the `token` field of the request body
+pub const TOKEN_HEADER: &str = "X-Fluxo-Token";
 
 #[derive(Debug, Clone, Serialize, FromOne)]
 pub enum Message {
 	SyncChanges(SyncChanges),
 	SyncbackChanges(SyncbackChanges),
 	SyncDetails(SyncDetails),
+	SyncSnapshot(SyncSnapshot),
 	ExecuteCode(ExecuteCode),
 	Disconnect(Disconnect),
+	Diagnostics(Diagnostics),
 }
 
 impl Message {
 	pub fn is_change(&self) -> bool {
-		matches!(self, Message::SyncChanges(_) | Message::SyncbackChanges(_))
+		matches!(
+			self,
+			Message::SyncChanges(_) | Message::SyncbackChanges(_) | Message::SyncSnapshot(_)
+		)
 	}
 }
 
@@ -50,6 +64,11 @@ pub struct SyncbackChanges();
 #[derive(Debug, Clone, Serialize)]
 pub struct SyncDetails(pub ProjectDetails);
 
+/// Whole instance tree sent by `fluxo sync`, replaces everything the client has,
+/// including its referent map, as the ids differ from the ones of the served tree
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncSnapshot(pub Box<AddedSnapshot>);
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ExecuteCode {
 	pub code: String,
@@ -60,16 +79,50 @@ pub struct Disconnect {
 	pub message: String,
 }
 
+/// Validation report, pushed only when live validation is enabled
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostics(pub Report);
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthRequest {
 	client_id: u32,
 }
 
+/// Random secret generated for every server, stored in its session entry
+#[derive(Debug, Clone)]
+pub struct Token(String);
+
+impl Token {
+	fn generate() -> Self {
+		Self(format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()))
+	}
+
+	pub fn matches(&self, token: &str) -> bool {
+		constant_eq(token.as_bytes(), self.0.as_bytes())
+	}
+
+	/// Returns `Unauthorized` response unless `request` carries this token,
+	/// either in the header or in the `field` taken from the request body
+	pub fn check(&self, request: &HttpRequest, field: Option<&str>) -> Option<HttpResponse> {
+		let header = request
+			.headers()
+			.get(TOKEN_HEADER)
+			.and_then(|value| value.to_str().ok());
+
+		match header.or(field) {
+			Some(token) if self.matches(token) => None,
+			Some(_) => Some(HttpResponse::Unauthorized().body("Invalid session token")),
+			None => Some(HttpResponse::Unauthorized().body("Missing session token")),
+		}
+	}
+}
+
 pub struct Server {
 	core: Arc<Core>,
 	host: String,
 	port: u16,
+	token: Token,
 }
 
 impl Server {
@@ -78,12 +131,20 @@ impl Server {
 			core,
 			host: host.to_owned(),
 			port,
+			token: Token::generate(),
 		}
 	}
 
+	/// Secret clients have to send with every mutating request
+	pub fn token(&self) -> &str {
+		&self.token.0
+	}
+
 	#[actix_web::main]
 	pub async fn start(&self) -> Result<()> {
 		let core = self.core.clone();
+		let synced = Data::new(sync::Synced::default());
+		let token = Data::new(self.token.clone());
 
 		HttpServer::new(move || {
 			let mut msgpack_config = MsgPackConfig::default();
@@ -91,17 +152,26 @@ impl Server {
 
 			App::new()
 				.app_data(Data::new(core.clone()))
+				.app_data(synced.clone())
+				.app_data(token.clone())
 				.app_data(msgpack_config)
+				.app_data(web::JsonConfig::default().limit(MAX_PAYLOAD_SIZE))
 				.service(details::main)
 				.service(subscribe::main)
 				.service(unsubscribe::main)
 				.service(snapshot::main)
 				.service(read::main)
+				.service(socket::main)
 				.service(write::main)
 				.service(exec::main)
 				.service(open::main)
 				.service(stop::main)
 				.service(home::main)
+				.service(health::main)
+				.service(sync::main)
+				.service(sync::changes)
+				.service(validate::main)
+				.service(publish::main)
 				.default_service(web::to(Self::default_redirect))
 		})
 		.backlog(0)
@@ -135,6 +205,11 @@ pub fn get_free_port(host: &str, port: u16) -> u16 {
 	port
 }
 
+/// Compare without returning early, so the time taken does not reveal the token
+fn constant_eq(a: &[u8], b: &[u8]) -> bool {
+	a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
 pub fn format_address(host: &str, port: u16) -> String {
 	format!("http://{}:{}", host, port)
 }
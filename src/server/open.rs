@@ -1,23 +1,34 @@
 use actix_msgpack::MsgPack;
-use actix_web::{post, web::Data, HttpResponse, Responder};
+use actix_web::{post, web::Data, HttpRequest, HttpResponse, Responder};
 use log::trace;
 use rbx_dom_weak::types::Ref;
 use serde::Deserialize;
 use std::sync::Arc;
 
-use crate::core::Core;
+use crate::{core::Core, server::Token};
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 struct Request {
 	instance: Ref,
 	_line: u32,
+	#[serde(default)]
+	token: Option<String>,
 }
 
 #[post("/open")]
-async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responder {
+async fn main(
+	http_request: HttpRequest,
+	request: MsgPack<Request>,
+	core: Data<Arc<Core>>,
+	token: Data<Token>,
+) -> impl Responder {
 	trace!("Received request: open");
 
+	if let Some(response) = token.check(&http_request, request.token.as_deref()) {
+		return response;
+	}
+
 	match core.open(request.instance) {
 		Ok(_) => HttpResponse::Ok().body("Opened file successfully"),
 		Err(err) => HttpResponse::InternalServerError().body(err.to_string()),
@@ -0,0 +1,67 @@
+use actix_web::{
+	post,
+	web::{Data, Json},
+	HttpRequest, HttpResponse, Responder,
+};
+use log::trace;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use crate::{
+	core::Core,
+	server::{sync::Synced, Token},
+};
+
+#[post("/publish")]
+async fn main(
+	http_request: HttpRequest,
+	request: Json<Value>,
+	core: Data<Arc<Core>>,
+	synced: Data<Synced>,
+	token: Data<Token>,
+) -> impl Responder {
+	trace!("Received request: publish");
+
+	if let Some(response) = token.check(&http_request, request["token"].as_str()) {
+		return response;
+	}
+
+	let mut request = request.into_inner();
+
+	// Secret must not end up in the publish dialog
+	if let Value::Object(map) = &mut request {
+		map.remove("token");
+	}
+
+	// Studio publishes the synced tree, so it has to be the plugin the client built
+	if let Some(expected) = request["hash"].as_str() {
+		let error = match synced.hash() {
+			Some(hash) if hash == expected => None,
+			Some(hash) => Some(format!(
+				"Studio is synced to plugin {}, not {}, sync the published sources first",
+				&hash[..hash.len().min(12)],
+				&expected[..expected.len().min(12)]
+			)),
+			None => Some(String::from(
+				"Nothing has been synced to Studio yet, run `fluxo sync` before publishing",
+			)),
+		};
+
+		if let Some(error) = error {
+			return HttpResponse::Ok().json(json!({
+				"success": false,
+				"error": error,
+			}));
+		}
+	}
+
+	let project = core.project();
+
+	// Studio shows the publish dialog with this data
+	HttpResponse::Ok().json(json!({
+		"success": true,
+		"metadata": request,
+		"projectPath": project.path,
+		"files": project.get_source_files().unwrap_or_default()
+	}))
+}
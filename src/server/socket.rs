@@ -0,0 +1,182 @@
+use actix_codec::{Decoder, Encoder};
+use actix_http::ws::{self, CloseCode, CloseReason, Codec, Frame, ProtocolError};
+use actix_web::{
+	get,
+	http::header::{self, HeaderValue},
+	rt,
+	web::{Bytes, BytesMut, Data, Payload, Query},
+	Error, HttpRequest, HttpResponse,
+};
+use futures_util::{stream, Stream, StreamExt};
+use log::{trace, warn};
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::{
+	constants::MAX_PAYLOAD_SIZE,
+	core::{processor::WriteRequest, Core},
+	server::{Message, Token},
+};
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Params {
+	client_id: u32,
+}
+
+/// Stream messages of a subscribed client as binary msgpack frames
+/// and accept its `WriteRequest`s on the same connection
+#[get("/socket")]
+async fn main(
+	request: HttpRequest,
+	payload: Payload,
+	params: Query<Params>,
+	core: Data<Arc<Core>>,
+	token: Data<Token>,
+) -> Result<HttpResponse, Error> {
+	trace!("Received request: socket");
+
+	let id = params.client_id;
+	let queue = core.queue();
+
+	if !queue.is_subscribed(id) {
+		return Ok(HttpResponse::Unauthorized().body("Not subscribed"));
+	}
+
+	ws::verify_handshake(request.head())?;
+
+	// Token sent in the handshake header authorizes all writes, otherwise every request needs its own
+	let authorized = token.check(&request, None).is_none();
+
+	let messages = queue.stream(id).map_err(actix_web::error::ErrorInternalServerError)?;
+	let (replies, replies_receiver) = mpsc::unbounded_channel();
+
+	rt::spawn(receive(
+		payload,
+		replies,
+		core.get_ref().clone(),
+		token.into_inner(),
+		id,
+		authorized,
+	));
+
+	let key = request
+		.headers()
+		.get(header::SEC_WEBSOCKET_KEY)
+		.expect("Key is checked by the handshake");
+
+	Ok(HttpResponse::SwitchingProtocols()
+		.upgrade("websocket")
+		.insert_header((
+			header::SEC_WEBSOCKET_ACCEPT,
+			HeaderValue::from_bytes(&ws::hash_key(key.as_bytes())).expect("Key is valid ASCII"),
+		))
+		.streaming(send(messages, replies_receiver)))
+}
+
+/// Encode queue messages and replies to the client into frames until the connection is closed
+fn send(
+	messages: UnboundedReceiver<Message>,
+	replies: UnboundedReceiver<ws::Message>,
+) -> impl Stream<Item = Result<Bytes, ProtocolError>> {
+	let state = (messages, replies, Codec::new().max_size(MAX_PAYLOAD_SIZE), false);
+
+	stream::unfold(state, |(mut messages, mut replies, mut codec, closed)| async move {
+		if closed {
+			return None;
+		}
+
+		let message = tokio::select! {
+			Some(reply) = replies.recv() => reply,
+			Some(message) = messages.recv() => match rmp_serde::to_vec_named(&message) {
+				Ok(data) => ws::Message::Binary(data.into()),
+				Err(err) => ws::Message::Close(Some(CloseReason {
+					code: CloseCode::Error,
+					description: Some(err.to_string()),
+				})),
+			},
+			else => return None,
+		};
+
+		let closed = matches!(message, ws::Message::Close(_));
+		let mut buffer = BytesMut::new();
+
+		let result = codec.encode(message, &mut buffer).map(|_| buffer.freeze());
+
+		Some((result, (messages, replies, codec, closed)))
+	})
+}
+
+/// Decode frames sent by the client and pass its writes to the processor
+async fn receive(
+	mut payload: Payload,
+	replies: UnboundedSender<ws::Message>,
+	core: Arc<Core>,
+	token: Arc<Token>,
+	id: u32,
+	authorized: bool,
+) {
+	let mut codec = Codec::new().max_size(MAX_PAYLOAD_SIZE);
+	let mut buffer = BytesMut::new();
+
+	let close = |code: CloseCode, description: String| {
+		replies
+			.send(ws::Message::Close(Some(CloseReason {
+				code,
+				description: Some(description),
+			})))
+			.ok();
+	};
+
+	loop {
+		let frame = match codec.decode(&mut buffer) {
+			Ok(Some(frame)) => frame,
+			Ok(None) => match payload.next().await {
+				Some(Ok(chunk)) => {
+					buffer.extend_from_slice(&chunk);
+					continue;
+				}
+				_ => return,
+			},
+			Err(err) => return close(CloseCode::Protocol, err.to_string()),
+		};
+
+		match frame {
+			Frame::Binary(data) => {
+				let mut request = match rmp_serde::from_slice::<WriteRequest>(&data) {
+					Ok(request) => request,
+					Err(err) => {
+						warn!("Received invalid write request over socket: {}", err);
+						continue;
+					}
+				};
+
+				let allowed = authorized || request.token.as_deref().is_some_and(|value| token.matches(value));
+
+				if !allowed {
+					return close(CloseCode::Policy, String::from("Missing or invalid session token"));
+				}
+
+				// Connection belongs to the client it was opened for
+				request.client_id = id;
+				core.processor().write(request);
+			}
+			Frame::Ping(data) => {
+				replies.send(ws::Message::Pong(data)).ok();
+			}
+			Frame::Close(reason) => {
+				replies.send(ws::Message::Close(reason)).ok();
+				return;
+			}
+			Frame::Text(_) => warn!("Received text frame over socket, only binary msgpack is supported"),
+			Frame::Pong(_) => {}
+			Frame::Continuation(_) => {
+				return close(
+					CloseCode::Unsupported,
+					String::from("Fragmented messages are not supported"),
+				);
+			}
+		}
+	}
+}
@@ -1,12 +1,18 @@
-use actix_web::{post, HttpResponse, Responder};
+use actix_web::{post, web::Data, HttpRequest, HttpResponse, Responder};
 use log::{info, trace};
 use std::process;
 
-use crate::util;
+use crate::{server::Token, util};
 
 #[post("/stop")]
-async fn main() -> impl Responder {
+async fn main(request: HttpRequest, token: Data<Token>) -> impl Responder {
 	trace!("Received request: stop");
+
+	// Request has no body so the token can only be sent in the header
+	if let Some(response) = token.check(&request, None) {
+		return response;
+	}
+
 	info!("Stopping Argon!");
 
 	util::kill_process(process::id());
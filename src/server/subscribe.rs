@@ -4,7 +4,7 @@ use log::trace;
 use serde::Deserialize;
 use std::sync::Arc;
 
-use crate::core::Core;
+use crate::{core::Core, server};
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -17,9 +17,15 @@ struct Request {
 async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responder {
 	trace!("Received request: subscribe");
 
-	let subscribed = core.queue().subscribe(request.client_id, &request.name);
+	let queue = core.queue();
+	let subscribed = queue.subscribe(request.client_id, &request.name);
 
 	if subscribed.is_ok() {
+		// Let the new client know about existing problems right away
+		if let Some(report) = core.processor().validation_report() {
+			queue.push(server::Diagnostics(report), Some(request.client_id)).ok();
+		}
+
 		HttpResponse::Ok().body("Subscribed successfully")
 	} else {
 		HttpResponse::BadRequest().body("Already subscribed")
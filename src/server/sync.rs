@@ -0,0 +1,95 @@
+use actix_web::{
+	post,
+	web::{Data, Json},
+	HttpRequest, HttpResponse, Responder,
+};
+use log::trace;
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+use crate::{
+	core::Core,
+	lock,
+	server::{SyncChanges, SyncSnapshot, Token},
+	sync::{ChangesRequest, SyncRequest, SyncResponse},
+};
+
+/// Content hash of the project as last synced by `fluxo sync`, `None` before the first sync
+#[derive(Default)]
+pub struct Synced(Mutex<Option<String>>);
+
+impl Synced {
+	pub fn hash(&self) -> Option<String> {
+		lock!(self.0).clone()
+	}
+}
+
+#[post("/sync")]
+async fn main(
+	http_request: HttpRequest,
+	request: Json<Value>,
+	core: Data<Arc<Core>>,
+	synced: Data<Synced>,
+	token: Data<Token>,
+) -> impl Responder {
+	trace!("Received request: sync");
+
+	if let Some(response) = token.check(&http_request, request["token"].as_str()) {
+		return response;
+	}
+
+	// Requests carry the whole instance tree built by `fluxo sync`
+	match serde_json::from_value::<SyncRequest>(request.into_inner()) {
+		Ok(request) => {
+			let mut synced = lock!(synced.0);
+
+			match core.queue().push(SyncSnapshot(Box::new(request.snapshot)), None) {
+				Ok(()) => {
+					*synced = Some(request.hash);
+					respond(true, false, None)
+				}
+				Err(err) => respond(false, false, Some(err.to_string())),
+			}
+		}
+		Err(err) => respond(false, false, Some(format!("Invalid sync request: {}", err))),
+	}
+}
+
+#[post("/sync/changes")]
+async fn changes(
+	http_request: HttpRequest,
+	request: Json<Value>,
+	core: Data<Arc<Core>>,
+	synced: Data<Synced>,
+	token: Data<Token>,
+) -> impl Responder {
+	trace!("Received request: sync changes");
+
+	if let Some(response) = token.check(&http_request, request["token"].as_str()) {
+		return response;
+	}
+
+	let request = match serde_json::from_value::<ChangesRequest>(request.into_inner()) {
+		Ok(request) => request,
+		Err(err) => return respond(false, false, Some(format!("Invalid changes request: {}", err))),
+	};
+
+	let mut synced = lock!(synced.0);
+
+	// Changes only apply on top of the state they were computed from
+	if synced.as_ref() != Some(&request.previous) {
+		return respond(false, true, None);
+	}
+
+	match core.queue().push(SyncChanges(request.changes), None) {
+		Ok(()) => {
+			*synced = Some(request.hash);
+			respond(true, false, None)
+		}
+		Err(err) => respond(false, false, Some(err.to_string())),
+	}
+}
+
+fn respond(success: bool, resync: bool, error: Option<String>) -> HttpResponse {
+	HttpResponse::Ok().json(SyncResponse { success, resync, error })
+}
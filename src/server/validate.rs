@@ -0,0 +1,22 @@
+use actix_web::{
+	post,
+	web::{self, Data},
+	HttpResponse, Responder,
+};
+use log::trace;
+use std::sync::Arc;
+
+use crate::{core::Core, validation::validator::Validator};
+
+#[post("/validate")]
+async fn main(core: Data<Arc<Core>>) -> impl Responder {
+	trace!("Received request: validate");
+
+	let workspace_dir = core.project().workspace_dir.clone();
+
+	// Validation reads every source file, so it must not block the async workers
+	match web::block(move || Validator::new().run(&workspace_dir)).await {
+		Ok(report) => HttpResponse::Ok().json(report),
+		Err(err) => HttpResponse::InternalServerError().body(err.to_string()),
+	}
+}
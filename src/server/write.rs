@@ -1,16 +1,28 @@
 use actix_msgpack::MsgPack;
-use actix_web::{post, web::Data, HttpResponse, Responder};
+use actix_web::{post, web::Data, HttpRequest, HttpResponse, Responder};
 use log::trace;
 use std::sync::Arc;
 
-use crate::core::{processor::WriteRequest, Core};
+use crate::{
+	core::{processor::WriteRequest, Core},
+	server::Token,
+};
 
 #[post("/write")]
-async fn main(request: MsgPack<WriteRequest>, core: Data<Arc<Core>>) -> impl Responder {
+async fn main(
+	http_request: HttpRequest,
+	request: MsgPack<WriteRequest>,
+	core: Data<Arc<Core>>,
+	token: Data<Token>,
+) -> impl Responder {
 	trace!("Received request: write");
 
 	let request = request.0;
 
+	if let Some(response) = token.check(&http_request, request.token.as_deref()) {
+		return response;
+	}
+
 	if !core.queue().is_subscribed(request.client_id) {
 		return HttpResponse::Unauthorized().body("Not subscribed");
 	}
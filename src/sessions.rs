@@ -1,15 +1,24 @@
 use anyhow::{Context, Result};
 use log::{debug, trace, warn};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fs, process, thread};
+use std::{
+	collections::HashMap,
+	fs,
+	io::Write,
+	path::{Path, PathBuf},
+	process, thread,
+};
 
-use crate::util;
+use crate::{config::Config, util};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct Session {
 	pub pid: u32,
 	pub host: Option<String>,
 	pub port: Option<u16>,
+	/// Secret required by mutating endpoints of the server
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub token: Option<String>,
 }
 
 impl Session {
@@ -30,8 +39,13 @@ struct Sessions {
 	active_sessions: HashMap<String, Session>,
 }
 
+/// File the sessions and their tokens are stored in
+pub fn get_path() -> Result<PathBuf> {
+	Ok(util::get_argon_dir()?.join("sessions.toml"))
+}
+
 fn get_sessions() -> Result<Sessions> {
-	let path = util::get_argon_dir()?.join("sessions.toml");
+	let path = get_path()?;
 
 	if path.exists() {
 		match toml::from_str(&fs::read_to_string(&path)?) {
@@ -45,23 +59,54 @@ fn get_sessions() -> Result<Sessions> {
 		active_sessions: HashMap::new(),
 	};
 
-	fs::write(path, toml::to_string(&sessions)?)?;
+	write(&path, &sessions)?;
 
 	Ok(sessions)
 }
 
 fn set_sessions(sessions: &Sessions) -> Result<()> {
-	let path = util::get_argon_dir()?.join("sessions.toml");
+	let path = get_path()?;
+
+	write(&path, sessions)
+}
+
+/// Session file holds tokens so only the owner can read it,
+/// permissions are set before anything is written to it
+fn write(path: &Path, sessions: &Sessions) -> Result<()> {
+	let mut options = fs::OpenOptions::new();
+	options.write(true).create(true).truncate(true);
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::OpenOptionsExt;
+		options.mode(0o600);
+	}
+
+	let mut file = options.open(path)?;
+
+	// Mode only applies to new files, older ones may still be readable by others
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::PermissionsExt;
+		file.set_permissions(fs::Permissions::from_mode(0o600))?;
+	}
 
-	fs::write(path, toml::to_string(sessions)?)?;
+	file.write_all(toml::to_string(sessions)?.as_bytes())?;
 
 	Ok(())
 }
 
-pub fn add(id: Option<String>, host: Option<String>, port: Option<u16>, pid: u32, run_async: bool) -> Result<()> {
+pub fn add(
+	id: Option<String>,
+	host: Option<String>,
+	port: Option<u16>,
+	token: Option<String>,
+	pid: u32,
+	run_async: bool,
+) -> Result<()> {
 	let mut sessions = get_sessions()?;
 
-	let session = Session { host, port, pid };
+	let session = Session { host, port, pid, token };
 	let id = id.unwrap_or(generate_id(&sessions));
 
 	sessions.last_session.clone_from(&id);
@@ -109,6 +154,33 @@ pub fn get(id: Option<String>, host: Option<String>, port: Option<u16>) -> Resul
 	Ok(None)
 }
 
+/// Returns the address of the last session when neither `host` nor `port`
+/// is given, otherwise the one built from them and the config defaults
+pub fn get_address(host: Option<String>, port: Option<u16>) -> Result<String> {
+	if host.is_none() && port.is_none() {
+		if let Some(address) = get(None, None, None)?.and_then(|session| session.get_address()) {
+			return Ok(address);
+		}
+	}
+
+	let config = Config::new();
+
+	Ok(format!(
+		"http://{}:{}",
+		host.unwrap_or(config.host.clone()),
+		port.unwrap_or(config.port)
+	))
+}
+
+/// Returns the token of the session serving on `address`, if there is one
+pub fn get_token(address: &str) -> Result<Option<String>> {
+	Ok(get_sessions()?
+		.active_sessions
+		.into_values()
+		.find(|session| session.get_address().as_deref() == Some(address))
+		.and_then(|session| session.token))
+}
+
 pub fn get_multiple(ids: &Vec<String>) -> Result<HashMap<String, Session>> {
 	let sessions = get_sessions()?;
 
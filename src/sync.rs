@@ -0,0 +1,210 @@
+use anyhow::Result;
+use rbx_dom_weak::types::Ref;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+	collections::BTreeMap,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	core::{
+		changes::Changes,
+		meta::{Meta, SourceEntry},
+		snapshot::{AddedSnapshot, Snapshot},
+		Core,
+	},
+	ext::ResultExt,
+	plugin::META_FILE,
+	publish::{
+		ledger::{diff_files, FileChange},
+		relative_path,
+	},
+	util,
+};
+
+/// Resolved project sent to Studio by `fluxo sync`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncRequest {
+	pub project_path: PathBuf,
+	/// Contents of `plugin.meta.json`, empty if there is none
+	pub metadata: Value,
+	/// Content hash of the project, the same `fluxo build` prints
+	pub hash: String,
+	/// Number of instances in the `snapshot`
+	pub instances: usize,
+	/// Whole instance tree with classes and properties
+	pub snapshot: AddedSnapshot,
+}
+
+impl SyncRequest {
+	/// Snapshot the tree of `core`, built from the project with
+	/// all its `$path` mappings, sync rules and `.meta.json` files
+	pub fn new(core: &Core) -> Result<Self> {
+		let (project_path, workspace_dir) = {
+			let project = core.project();
+			(project.path.clone(), project.workspace_dir.clone())
+		};
+
+		let meta_path = workspace_dir.join(META_FILE);
+
+		let metadata = if meta_path.exists() {
+			let contents = fs::read_to_string(&meta_path).desc("Failed to read plugin metadata")?;
+			serde_json::from_str(&contents).desc("Failed to parse plugin metadata")?
+		} else {
+			Value::Object(Default::default())
+		};
+
+		let snapshot = core.snapshot(Ref::none()).expect("Tree always has a root");
+
+		Ok(Self {
+			project_path,
+			metadata,
+			hash: content_hash(core)?,
+			instances: 1 + count(&snapshot.children),
+			snapshot,
+		})
+	}
+}
+
+/// Instances changed since the last sync, sent by `fluxo sync --watch`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangesRequest {
+	pub project_path: PathBuf,
+	/// Content hash the client must have for the changes to apply
+	pub previous: String,
+	/// Content hash of the project with the changes applied
+	pub hash: String,
+	pub changes: Changes,
+}
+
+impl ChangesRequest {
+	pub fn new(core: &Core, previous: String, changes: Changes) -> Result<Self> {
+		let project_path = core.project().path.clone();
+
+		Ok(Self {
+			project_path,
+			previous,
+			hash: content_hash(core)?,
+			changes,
+		})
+	}
+}
+
+/// Reply to both sync requests
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncResponse {
+	pub success: bool,
+	/// Set when the client is out of sync and needs the whole snapshot again
+	#[serde(default)]
+	pub resync: bool,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+/// Content hashes of all files the tree of a `Core` was built from,
+/// kept up to date with the changes found by its processor
+#[derive(Debug, Clone, Default)]
+pub struct Manifest {
+	workspace_dir: PathBuf,
+	/// Hashes keyed by path relative to the workspace
+	files: BTreeMap<String, String>,
+}
+
+impl Manifest {
+	pub fn new(core: &Core) -> Self {
+		let workspace_dir = core.project().workspace_dir.clone();
+
+		let files = core
+			.source_files()
+			.into_iter()
+			.filter_map(|path| Some((relative_path(&path, &workspace_dir), hash_file(&path)?)))
+			.collect();
+
+		Self { workspace_dir, files }
+	}
+
+	/// Hash the files of the instances in `changes` again and drop updates
+	/// of instances whose files did not change, returns the changed files
+	pub fn update(&mut self, core: &Core, changes: &mut Changes) -> Vec<(String, FileChange)> {
+		let current = core.source_files();
+
+		// Files of every updated instance, `None` if it has no meta
+		let updated = {
+			let tree = core.tree();
+
+			changes
+				.updates
+				.iter()
+				.map(|update| {
+					let meta = tree.get_meta(update.id)?;
+
+					Some(
+						source_files(meta)
+							.map(|path| relative_path(path, &self.workspace_dir))
+							.collect::<Vec<_>>(),
+					)
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// Only new files and files of updated instances are read again
+		let mut files = BTreeMap::new();
+
+		for path in current {
+			let relative = relative_path(&path, &self.workspace_dir);
+			let is_updated = updated.iter().flatten().any(|paths| paths.contains(&relative));
+
+			let hash = match self.files.get(&relative) {
+				Some(hash) if !is_updated => Some(hash.clone()),
+				_ => hash_file(&path),
+			};
+
+			if let Some(hash) = hash {
+				files.insert(relative, hash);
+			}
+		}
+
+		let mut updated = updated.into_iter();
+
+		// Instances without files, like folders, are always sent
+		changes.updates.retain(|_| match updated.next().flatten() {
+			Some(paths) if !paths.is_empty() => paths.iter().any(|path| self.files.get(path) != files.get(path)),
+			_ => true,
+		});
+
+		let changed = diff_files(&self.files, &files);
+		self.files = files;
+
+		changed
+	}
+}
+
+/// Returns the hash `fluxo build` prints for the tree of `core`
+pub fn content_hash(core: &Core) -> Result<String> {
+	let mut data = Vec::new();
+	core.write(&mut data, false).desc("Failed to build project")?;
+
+	Ok(util::hash(data))
+}
+
+/// Returns the files an instance was built from, without its directory
+fn source_files(meta: &Meta) -> impl Iterator<Item = &Path> {
+	meta.source
+		.relevant()
+		.iter()
+		.filter(|entry| !matches!(entry, SourceEntry::Folder(_)))
+		.map(|entry| entry.path())
+}
+
+fn hash_file(path: &Path) -> Option<String> {
+	fs::read(path).ok().map(util::hash)
+}
+
+fn count(children: &[Snapshot]) -> usize {
+	children.iter().map(|child| 1 + count(&child.children)).sum()
+}
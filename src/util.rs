@@ -7,6 +7,7 @@ use log::LevelFilter;
 use rbx_dom_weak::types::Variant;
 use rbx_reflection::ClassTag;
 use roblox_install::RobloxStudio;
+use sha2::{Digest, Sha256};
 use std::{env, path::PathBuf, process::Command};
 
 use crate::Properties;
@@ -53,6 +54,11 @@ pub fn is_script(class: &str) -> bool {
 	class == "Script" || class == "LocalScript" || class == "ModuleScript"
 }
 
+/// Returns hex encoded SHA-256 hash of the given `data`
+pub fn hash(data: impl AsRef<[u8]>) -> String {
+	format!("{:x}", Sha256::digest(data))
+}
+
 /// Kills the process with the given `pid`
 pub fn kill_process(pid: u32) {
 	#[cfg(not(target_os = "windows"))]
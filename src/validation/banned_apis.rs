@@ -0,0 +1,99 @@
+use std::path::Path;
+
+use super::{
+	lexer::{self, Token},
+	line_snippet, Diagnostic, Severity,
+};
+
+pub const RULE: &str = "banned-api";
+
+/// APIs that are not allowed in the published plugins by default
+pub const DEFAULT_BANNED_APIS: [&str; 4] = ["loadstring", "getfenv", "setfenv", "debug.getupvalue"];
+
+/// Single occurrence of the banned API in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct BannedApiMatch<'a> {
+	pub api: &'a str,
+	pub line: usize,
+	pub column: usize,
+}
+
+/// Find all call or index expressions of the given `apis` (like `loadstring`
+/// or `debug.getupvalue`), ignoring comments, strings, declarations
+/// and identifiers that only contain the API name
+pub fn find<'a, S: AsRef<str>>(source: &str, apis: &'a [S]) -> Vec<BannedApiMatch<'a>> {
+	let tokens = lexer::tokenize_code(source);
+	let mut matches = Vec::new();
+
+	for (index, token) in tokens.iter().enumerate() {
+		for api in apis {
+			let api = api.as_ref();
+
+			if matches_at(&tokens, index, api) {
+				matches.push(BannedApiMatch {
+					api,
+					line: token.line,
+					column: token.column,
+				});
+			}
+		}
+	}
+
+	matches
+}
+
+/// Find banned APIs and turn them into diagnostics
+pub fn check<S: AsRef<str>>(path: &Path, source: &str, apis: &[S], severity: Severity) -> Vec<Diagnostic> {
+	find(source, apis)
+		.into_iter()
+		.map(|found| {
+			Diagnostic::new(RULE, severity, format!("Banned API '{}' is used", found.api))
+				.with_path(path)
+				.with_location(found.line, found.column)
+				.with_snippet(line_snippet(source, found.line))
+		})
+		.collect()
+}
+
+fn matches_at(tokens: &[Token], index: usize, api: &str) -> bool {
+	let mut segments = api.split('.');
+
+	if !segments.next().is_some_and(|first| tokens[index].is_name(first)) {
+		return false;
+	}
+
+	// Member of some other table (`foo.loadstring`) or a declaration (`function loadstring()`)
+	if let Some(previous) = index.checked_sub(1).map(|i| &tokens[i]) {
+		if previous.is_symbol(".") || previous.is_symbol(":") || previous.is_keyword("function") {
+			return false;
+		}
+	}
+
+	let mut is_index = false;
+	let mut next = index + 1;
+
+	for segment in segments {
+		let get = |offset: usize| tokens.get(next + offset);
+
+		if get(0).is_some_and(|t| t.is_symbol(".")) && get(1).is_some_and(|t| t.is_name(segment)) {
+			next += 2;
+		} else if get(0).is_some_and(|t| t.is_symbol("["))
+			&& get(1).is_some_and(|t| t.is_string() && t.value == segment)
+			&& get(2).is_some_and(|t| t.is_symbol("]"))
+		{
+			next += 3;
+		} else {
+			return false;
+		}
+
+		is_index = true;
+	}
+
+	if is_index {
+		return true;
+	}
+
+	tokens.get(next).is_some_and(|token| {
+		token.is_string() || ["(", "{", ".", ":", "["].iter().any(|symbol| token.is_symbol(symbol))
+	})
+}
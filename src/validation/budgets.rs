@@ -0,0 +1,187 @@
+use anyhow::Result;
+use rbx_dom_weak::{
+	types::{Ref, Variant},
+	ustr, WeakDom,
+};
+use std::path::PathBuf;
+
+use super::{full_name, Diagnostic, Severity};
+use crate::{plugin::config::BudgetConfig, util};
+
+pub const RULE: &str = "budgets";
+pub const SOURCE_RULE: &str = "source-length";
+pub const INSTANCES_RULE: &str = "instance-budget";
+pub const LOC_RULE: &str = "loc-budget";
+pub const SIZE_RULE: &str = "size-budget";
+
+/// Usage above this fraction of the budget is reported as a warning
+pub const WARNING_RATIO: f64 = 0.9;
+
+/// How many of the largest contributors are listed
+const CONTRIBUTORS: usize = 5;
+
+/// Totals measured for the plugin
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Usage {
+	pub instances: usize,
+	pub loc: usize,
+	pub size: usize,
+}
+
+/// Check `roots` and all of their descendants against the `budgets`,
+/// `path_of` returns the source file of the given instance if it has one
+pub fn check(
+	dom: &WeakDom,
+	roots: &[Ref],
+	budgets: &BudgetConfig,
+	path_of: impl Fn(Ref) -> Option<PathBuf>,
+) -> Result<(Usage, Vec<Diagnostic>)> {
+	let mut usage = Usage::default();
+	let mut diagnostics = Vec::new();
+
+	let mut scripts = Vec::new();
+
+	for &root in roots {
+		for instance in dom.descendants_of(root) {
+			let loc = util::count_loc_from_properties(&instance.properties);
+
+			usage.instances += 1;
+			usage.loc += loc;
+
+			if loc > 0 {
+				scripts.push((instance.referent(), loc));
+			}
+
+			if !util::is_script(&instance.class) {
+				continue;
+			}
+
+			let length = match instance.properties.get(&ustr("Source")) {
+				Some(Variant::String(source)) => source.chars().count(),
+				_ => continue,
+			};
+
+			if let Some(severity) = severity(length, budgets.max_source_length) {
+				let mut diagnostic = Diagnostic::new(
+					SOURCE_RULE,
+					severity,
+					format!(
+						"Script {} is {} characters long, the limit is {}",
+						full_name(dom, instance.referent()),
+						length,
+						budgets.max_source_length
+					),
+				);
+
+				if let Some(path) = path_of(instance.referent()) {
+					diagnostic = diagnostic.with_path(&path);
+				}
+
+				diagnostics.push(diagnostic);
+			}
+		}
+	}
+
+	usage.size = serialized_size(dom, roots)?;
+
+	// Direct children of a single root are more useful than the root itself
+	let parts = match roots {
+		[root] => dom.get_by_ref(*root).unwrap().children().to_vec(),
+		_ => roots.to_vec(),
+	};
+
+	let instances = || {
+		parts
+			.iter()
+			.map(|&part| (part, dom.descendants_of(part).count()))
+			.collect()
+	};
+
+	// Serializing every part is slow so it is done only when needed
+	let sizes = || {
+		parts
+			.iter()
+			.map(|&part| (part, serialized_size(dom, &[part]).unwrap_or_default()))
+			.collect()
+	};
+
+	diagnostics.extend(budget(
+		dom,
+		INSTANCES_RULE,
+		"instances",
+		usage.instances,
+		budgets.max_instances,
+		instances,
+	));
+	diagnostics.extend(budget(
+		dom,
+		LOC_RULE,
+		"lines of code",
+		usage.loc,
+		budgets.max_loc,
+		|| scripts,
+	));
+	diagnostics.extend(budget(dom, SIZE_RULE, "bytes", usage.size, budgets.max_size, sizes));
+
+	diagnostics.push(Diagnostic::new(
+		RULE,
+		Severity::Info,
+		format!(
+			"Plugin has {}/{} instances, {}/{} lines of code and {}/{} bytes",
+			usage.instances, budgets.max_instances, usage.loc, budgets.max_loc, usage.size, budgets.max_size
+		),
+	));
+
+	Ok((usage, diagnostics))
+}
+
+/// Returns size of `roots` serialized in the binary format
+pub fn serialized_size(dom: &WeakDom, roots: &[Ref]) -> Result<usize> {
+	let mut buffer = Vec::new();
+	rbx_binary::to_writer(&mut buffer, dom, roots)?;
+
+	Ok(buffer.len())
+}
+
+fn severity(used: usize, limit: usize) -> Option<Severity> {
+	if used > limit {
+		Some(Severity::Error)
+	} else if used as f64 >= limit as f64 * WARNING_RATIO {
+		Some(Severity::Warning)
+	} else {
+		None
+	}
+}
+
+fn budget(
+	dom: &WeakDom,
+	rule: &str,
+	unit: &str,
+	used: usize,
+	limit: usize,
+	contributors: impl FnOnce() -> Vec<(Ref, usize)>,
+) -> Option<Diagnostic> {
+	let severity = severity(used, limit)?;
+
+	let mut contributors = contributors();
+	contributors.sort_by_key(|(_, amount)| std::cmp::Reverse(*amount));
+
+	let largest = contributors
+		.iter()
+		.take(CONTRIBUTORS)
+		.map(|(id, amount)| format!("{} ({})", full_name(dom, *id), amount))
+		.collect::<Vec<_>>()
+		.join(", ");
+
+	let message = if severity == Severity::Error {
+		format!("Plugin has {} {}, the limit is {}", used, unit, limit)
+	} else {
+		format!("Plugin has {} {}, close to the limit of {}", used, unit, limit)
+	};
+
+	Some(if largest.is_empty() {
+		Diagnostic::new(rule, severity, message)
+	} else {
+		Diagnostic::new(rule, severity, format!("{}, largest: {}", message, largest))
+	})
+}
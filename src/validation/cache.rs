@@ -0,0 +1,115 @@
+use anyhow::Result;
+use log::trace;
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::BTreeMap,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use super::Diagnostic;
+use crate::util;
+
+/// Directory with all Fluxo caches, relative to the workspace
+pub const CACHE_DIR: &str = ".fluxo/cache";
+
+const CACHE_FILE: &str = "validation.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Entry {
+	hash: String,
+	diagnostics: Vec<Diagnostic>,
+}
+
+/// Per file validation results, valid as long as
+/// both file contents and the rule set stay the same
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Cache {
+	rules: String,
+	files: BTreeMap<PathBuf, Entry>,
+
+	#[serde(skip)]
+	path: PathBuf,
+	#[serde(skip)]
+	changed: bool,
+}
+
+impl Cache {
+	/// Load cache of the given workspace, starting fresh if
+	/// it does not exist, is corrupted or `rules` hash changed
+	pub fn load(workspace_dir: &Path, rules: &str) -> Self {
+		let path = workspace_dir.join(CACHE_DIR).join(CACHE_FILE);
+
+		let cache = fs::read_to_string(&path)
+			.ok()
+			.and_then(|cache| serde_json::from_str::<Cache>(&cache).ok())
+			.filter(|cache| cache.rules == rules);
+
+		match cache {
+			Some(cache) => Self { path, ..cache },
+			None => {
+				trace!("Validation cache is missing or outdated");
+
+				Self {
+					rules: rules.to_owned(),
+					path,
+					changed: true,
+					..Default::default()
+				}
+			}
+		}
+	}
+
+	/// Returns cached diagnostics of the file at `path` if its content `hash` matches
+	pub fn get(&self, path: &Path, hash: &str) -> Option<&Vec<Diagnostic>> {
+		self.files
+			.get(path)
+			.filter(|entry| entry.hash == hash)
+			.map(|entry| &entry.diagnostics)
+	}
+
+	/// Returns cached diagnostics of the file at `path` without
+	/// comparing its content, for files that are known to be unchanged
+	pub fn last(&self, path: &Path) -> Option<&Vec<Diagnostic>> {
+		self.files.get(path).map(|entry| &entry.diagnostics)
+	}
+
+	pub fn insert(&mut self, path: &Path, hash: &str, diagnostics: Vec<Diagnostic>) {
+		self.files.insert(
+			path.to_owned(),
+			Entry {
+				hash: hash.to_owned(),
+				diagnostics,
+			},
+		);
+
+		self.changed = true;
+	}
+
+	/// Remove entries of files that are not in `paths` anymore
+	pub fn retain(&mut self, paths: &[PathBuf]) {
+		let count = self.files.len();
+
+		self.files.retain(|path, _| paths.contains(path));
+		self.changed |= count != self.files.len();
+	}
+
+	/// Write the cache to disk if anything changed
+	pub fn save(&self) -> Result<()> {
+		if !self.changed {
+			return Ok(());
+		}
+
+		fs::create_dir_all(self.path.parent().unwrap())?;
+		fs::write(&self.path, serde_json::to_string(self)?)?;
+
+		Ok(())
+	}
+}
+
+/// Returns hash of everything that affects results of per file checks
+pub fn rules_hash(parts: &impl Serialize) -> Result<String> {
+	let parts = serde_json::to_string(parts)?;
+
+	Ok(util::hash(format!("{}{}", env!("CARGO_PKG_VERSION"), parts)))
+}
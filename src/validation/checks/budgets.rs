@@ -0,0 +1,66 @@
+use anyhow::Result;
+
+use super::{Check, Context};
+use crate::validation::{budgets, Diagnostic};
+
+pub use crate::validation::budgets::RULE;
+
+/// Checks script length, instance count, lines of code
+/// and serialized size of the built project
+pub struct BudgetsCheck {
+	always: bool,
+}
+
+impl BudgetsCheck {
+	/// Run only if budgets are set in `fluxo.config.json`
+	pub fn new() -> Self {
+		Self { always: false }
+	}
+
+	/// Run even without configured budgets, using the default limits
+	pub fn always() -> Self {
+		Self { always: true }
+	}
+}
+
+impl Default for BudgetsCheck {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Check for BudgetsCheck {
+	fn name(&self) -> &'static str {
+		RULE
+	}
+
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+		let budgets = match &context.config.validation.budgets {
+			Some(budgets) => budgets.clone(),
+			None if self.always => Default::default(),
+			None => return Ok(Vec::new()),
+		};
+
+		let core = context.core()?;
+		let is_place = core.project().is_place();
+
+		let tree = core.tree();
+
+		let roots = if is_place {
+			tree.place_root_refs().to_vec()
+		} else {
+			vec![tree.root_ref()]
+		};
+
+		let path_of = |id| {
+			let meta = tree.get_meta(id)?;
+			let path = meta.source.get_file()?.path();
+
+			Some(path.strip_prefix(&context.workspace_dir).unwrap_or(path).to_owned())
+		};
+
+		let (_, diagnostics) = budgets::check(tree.inner(), &roots, &budgets, path_of)?;
+
+		Ok(diagnostics)
+	}
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+
+use super::{Check, Context};
+use crate::{
+	plugin::{icon, metadata::PluginMetadata, META_FILE},
+	validation::Diagnostic,
+};
+
+pub use crate::plugin::icon::RULE;
+
+/// Validates format, dimensions and size of the plugin icon
+pub struct IconCheck;
+
+impl Check for IconCheck {
+	fn name(&self) -> &'static str {
+		RULE
+	}
+
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+		// Without metadata this is not a plugin, which the structure check reports
+		if !context.workspace_dir.join(META_FILE).exists() {
+			return Ok(Vec::new());
+		}
+
+		let metadata = PluginMetadata::load(&context.workspace_dir).ok();
+
+		Ok(icon::validate(&context.workspace_dir, metadata.as_ref()))
+	}
+}
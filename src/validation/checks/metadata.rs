@@ -0,0 +1,33 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::{Check, Context};
+use crate::{
+	plugin::{metadata::PluginMetadata, META_FILE},
+	validation::{Diagnostic, Severity},
+};
+
+pub use crate::plugin::metadata::RULE;
+
+/// Validates the `plugin.meta.json` file against `PluginMetadata` rules
+pub struct MetadataCheck;
+
+impl Check for MetadataCheck {
+	fn name(&self) -> &'static str {
+		RULE
+	}
+
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+		// Missing file is already reported by the structure check
+		if !context.workspace_dir.join(META_FILE).exists() {
+			return Ok(Vec::new());
+		}
+
+		Ok(match PluginMetadata::load(&context.workspace_dir) {
+			Ok(metadata) => metadata.validate(&context.workspace_dir),
+			Err(err) => {
+				vec![Diagnostic::new(RULE, Severity::Error, err.to_string()).with_path(Path::new(META_FILE))]
+			}
+		})
+	}
+}
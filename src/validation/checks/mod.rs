@@ -0,0 +1,117 @@
+use anyhow::Result;
+use std::{
+	cell::OnceCell,
+	path::{Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+use super::Diagnostic;
+use crate::{
+	core::Core,
+	plugin::config::PluginConfig,
+	project::{self, Project},
+};
+
+pub mod budgets;
+pub mod icon;
+pub mod metadata;
+pub mod properties;
+pub mod requires;
+pub mod sources;
+pub mod structure;
+
+/// Single, independent validation step run by the `Validator`
+pub trait Check {
+	/// Unique name used to skip the check and to report its failures
+	fn name(&self) -> &'static str;
+
+	/// Returning an error means the check could not be completed,
+	/// problems found in the project should be returned as diagnostics
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>>;
+}
+
+/// Everything the checks can inspect
+pub struct Context {
+	pub workspace_dir: PathBuf,
+	pub config: PluginConfig,
+	/// Whether checks can reuse results cached in `.fluxo/cache`
+	pub use_cache: bool,
+	/// Files changed since the last run, relative to the workspace directory,
+	/// `None` if every file has to be checked
+	pub changed: Option<Vec<PathBuf>>,
+	core: OnceCell<Core>,
+}
+
+impl Context {
+	pub fn new(workspace_dir: &Path, config: PluginConfig) -> Self {
+		Self {
+			workspace_dir: workspace_dir.to_owned(),
+			config,
+			use_cache: false,
+			changed: None,
+			core: OnceCell::new(),
+		}
+	}
+
+	pub fn with_cache(mut self, use_cache: bool) -> Self {
+		self.use_cache = use_cache;
+		self
+	}
+
+	pub fn with_changed(mut self, changed: Option<Vec<PathBuf>>) -> Self {
+		self.changed = changed;
+		self
+	}
+
+	/// Returns whether the file at `path` could have changed since the last run
+	pub fn is_changed(&self, path: &Path) -> bool {
+		self.changed
+			.as_ref()
+			.is_none_or(|changed| changed.iter().any(|changed| path.starts_with(changed)))
+	}
+
+	/// Returns the `Core` with the built project tree,
+	/// the project is built only once, when first needed
+	pub fn core(&self) -> Result<&Core> {
+		if let Some(core) = self.core.get() {
+			return Ok(core);
+		}
+
+		let project = Project::load(&project::resolve(self.workspace_dir.clone())?)?;
+		let core = Core::new(project, false)?;
+
+		Ok(self.core.get_or_init(|| core))
+	}
+
+	/// Returns paths of all Lua and Luau files in the `src` directory,
+	/// relative to the workspace directory
+	pub fn source_files(&self) -> Result<Vec<PathBuf>> {
+		let mut files = Vec::new();
+
+		for entry in WalkDir::new(self.workspace_dir.join("src")).sort_by_file_name() {
+			let entry = entry?;
+			let path = entry.path();
+
+			if !entry.file_type().is_file() {
+				continue;
+			}
+
+			if path.extension().is_some_and(|ext| ext == "lua" || ext == "luau") {
+				files.push(path.strip_prefix(&self.workspace_dir)?.to_owned());
+			}
+		}
+
+		Ok(files)
+	}
+}
+
+/// Checks run by default, in order
+pub fn defaults() -> Vec<Box<dyn Check>> {
+	vec![
+		Box::new(structure::StructureCheck),
+		Box::new(metadata::MetadataCheck),
+		Box::new(icon::IconCheck),
+		Box::new(sources::SourcesCheck),
+		Box::new(budgets::BudgetsCheck::new()),
+	]
+}
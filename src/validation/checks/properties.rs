@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use super::{Check, Context};
+use crate::validation::{properties, Diagnostic};
+
+pub const RULE: &str = "properties";
+
+/// Checks classes and properties from the project file, `.meta.json`
+/// and `.model.json` files against the reflection database
+pub struct PropertiesCheck;
+
+impl Check for PropertiesCheck {
+	fn name(&self) -> &'static str {
+		RULE
+	}
+
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+		let core = context.core()?;
+
+		properties::check_tree(&core.tree(), &context.workspace_dir)
+	}
+}
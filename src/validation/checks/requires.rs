@@ -0,0 +1,49 @@
+use anyhow::Result;
+use std::{fs, path::PathBuf};
+
+use super::{Check, Context};
+use crate::{ext::ResultExt, validation::requires, validation::Diagnostic};
+
+pub const RULE: &str = "requires";
+
+/// Resolves requires in the built project tree and reports
+/// unresolved targets, cycles and requires of non-ModuleScripts
+#[derive(Default)]
+pub struct RequiresCheck {
+	graph: Option<PathBuf>,
+}
+
+impl RequiresCheck {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Write the require graph to `path`, as Mermaid flowchart
+	/// if the extension is `mmd` or `mermaid`, DOT otherwise
+	pub fn with_graph(mut self, path: PathBuf) -> Self {
+		self.graph = Some(path);
+		self
+	}
+}
+
+impl Check for RequiresCheck {
+	fn name(&self) -> &'static str {
+		RULE
+	}
+
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+		let core = context.core()?;
+		let analysis = requires::analyze(&core.tree(), &context.workspace_dir);
+
+		if let Some(path) = &self.graph {
+			let graph = match path.extension().and_then(|ext| ext.to_str()) {
+				Some("mmd" | "mermaid") => analysis.graph.to_mermaid(),
+				_ => analysis.graph.to_dot(),
+			};
+
+			fs::write(path, graph).with_desc(|| format!("Failed to write require graph to {}", path.display()))?;
+		}
+
+		Ok(analysis.diagnostics)
+	}
+}
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use log::warn;
+use std::fs;
+
+use super::{Check, Context};
+use crate::{
+	util,
+	validation::{
+		cache::{self, Cache},
+		rules::RuleSet,
+		Diagnostic, Severity,
+	},
+};
+
+pub const RULE: &str = "sources";
+
+/// Runs banned API and custom rules from `fluxo.config.json` on all sources
+pub struct SourcesCheck;
+
+impl Check for SourcesCheck {
+	fn name(&self) -> &'static str {
+		RULE
+	}
+
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+		let rules = RuleSet::new(&context.config.validation)?;
+		let files = context.source_files()?;
+
+		let mut diagnostics = Vec::new();
+
+		if files.is_empty() && context.workspace_dir.join("src").exists() {
+			diagnostics.push(Diagnostic::new(
+				RULE,
+				Severity::Warning,
+				"No source files found in 'src'",
+			));
+		}
+
+		let mut cache = if context.use_cache {
+			Some(Cache::load(
+				&context.workspace_dir,
+				&cache::rules_hash(&context.config.validation)?,
+			))
+		} else {
+			None
+		};
+
+		for path in &files {
+			if !rules.is_included(path) {
+				continue;
+			}
+
+			// Unchanged files are not even read again during live validation
+			if !context.is_changed(path) {
+				if let Some(cached) = cache.as_ref().and_then(|cache| cache.last(path)) {
+					diagnostics.extend(cached.iter().cloned());
+					continue;
+				}
+			}
+
+			let source = fs::read_to_string(context.workspace_dir.join(path))?;
+			let hash = util::hash(&source);
+
+			if let Some(cached) = cache.as_ref().and_then(|cache| cache.get(path, &hash)) {
+				diagnostics.extend(cached.iter().cloned());
+				continue;
+			}
+
+			let found = rules.check(path, &source);
+
+			if let Some(cache) = &mut cache {
+				cache.insert(path, &hash, found.clone());
+			}
+
+			diagnostics.extend(found);
+		}
+
+		if let Some(mut cache) = cache {
+			cache.retain(&files);
+
+			if let Err(err) = cache.save() {
+				warn!("Failed to save validation cache: {}", err);
+			}
+		}
+
+		Ok(diagnostics)
+	}
+}
@@ -0,0 +1,38 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::{Check, Context};
+use crate::{
+	plugin::{CONFIG_FILE, META_FILE},
+	validation::{Diagnostic, Severity},
+};
+
+pub const RULE: &str = "structure";
+
+/// Makes sure required files and directories exist
+pub struct StructureCheck;
+
+impl Check for StructureCheck {
+	fn name(&self) -> &'static str {
+		RULE
+	}
+
+	fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+		let mut diagnostics = Vec::new();
+
+		// `fluxo.config.json` is optional, defaults are used without it
+		for (name, kind, severity) in [
+			("src", "directory", Severity::Error),
+			(META_FILE, "file", Severity::Error),
+			(CONFIG_FILE, "file", Severity::Warning),
+		] {
+			if !context.workspace_dir.join(name).exists() {
+				diagnostics.push(
+					Diagnostic::new(RULE, severity, format!("Missing '{}' {}", name, kind)).with_path(Path::new(name)),
+				);
+			}
+		}
+
+		Ok(diagnostics)
+	}
+}
@@ -0,0 +1,244 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use colored::{ColoredString, Colorize};
+use serde_json::json;
+use std::{collections::BTreeMap, path::Path};
+
+use super::{report::Report, Diagnostic, Severity};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+	/// Human readable, colored output
+	#[default]
+	Text,
+	/// Serialized validation report
+	Json,
+	/// Static Analysis Results Interchange Format 2.1.0
+	Sarif,
+	/// JUnit XML test report
+	Junit,
+}
+
+/// Render the report in any machine-readable format, `fail_on` decides
+/// which diagnostics are reported as failures in formats that need it
+pub fn render(report: &Report, format: OutputFormat, fail_on: Severity) -> Result<String> {
+	Ok(match format {
+		OutputFormat::Text => to_text(report, false),
+		OutputFormat::Json => serde_json::to_string_pretty(report)?,
+		OutputFormat::Sarif => to_sarif(report)?,
+		OutputFormat::Junit => to_junit(report, fail_on),
+	})
+}
+
+/// Colored, human readable list of warnings and errors,
+/// informational diagnostics are included only when `verbose`
+pub fn to_text(report: &Report, verbose: bool) -> String {
+	let mut text = String::new();
+
+	let mut section = |title: ColoredString, diagnostics: Vec<&Diagnostic>, bullet: fn(&str) -> ColoredString| {
+		if diagnostics.is_empty() {
+			return;
+		}
+
+		text.push_str(&format!("\n{}\n", title));
+
+		for diagnostic in diagnostics {
+			text.push_str(&format!("  {} {}\n", bullet("•"), diagnostic));
+
+			if let Some(snippet) = &diagnostic.snippet {
+				text.push_str(&format!("      {} {}\n", "|".dimmed(), snippet.dimmed()));
+			}
+		}
+	};
+
+	if verbose {
+		section(
+			"ℹ️  Info:".blue().bold(),
+			report.with_severity(Severity::Info).collect(),
+			|s| s.blue(),
+		);
+	}
+
+	section(
+		"⚠️  Warnings:".yellow().bold(),
+		report.with_severity(Severity::Warning).collect(),
+		|s| s.yellow(),
+	);
+	section(
+		"❌ Errors:".red().bold(),
+		report.with_severity(Severity::Error).collect(),
+		|s| s.red(),
+	);
+
+	text
+}
+
+pub fn to_sarif(report: &Report) -> Result<String> {
+	let mut rules = BTreeMap::new();
+
+	for diagnostic in &report.diagnostics {
+		rules.entry(diagnostic.rule.as_str()).or_insert_with(|| {
+			json!({
+				"id": diagnostic.rule,
+				"shortDescription": { "text": diagnostic.rule },
+			})
+		});
+	}
+
+	let results: Vec<_> = report
+		.diagnostics
+		.iter()
+		.map(|diagnostic| {
+			let mut result = json!({
+				"ruleId": diagnostic.rule,
+				"level": match diagnostic.severity {
+					Severity::Error => "error",
+					Severity::Warning => "warning",
+					Severity::Info => "note",
+				},
+				"message": { "text": diagnostic.message },
+			});
+
+			if let Some(path) = &diagnostic.path {
+				let mut location = json!({
+					"physicalLocation": {
+						"artifactLocation": { "uri": to_uri(path) },
+					}
+				});
+
+				if let Some(line) = diagnostic.line {
+					let mut region = json!({ "startLine": line });
+
+					if let Some(column) = diagnostic.column {
+						region["startColumn"] = column.into();
+					}
+
+					if let Some(snippet) = &diagnostic.snippet {
+						region["snippet"] = json!({ "text": snippet });
+					}
+
+					location["physicalLocation"]["region"] = region;
+				}
+
+				result["locations"] = json!([location]);
+			}
+
+			result
+		})
+		.collect();
+
+	let sarif = json!({
+		"$schema": SARIF_SCHEMA,
+		"version": "2.1.0",
+		"runs": [{
+			"tool": {
+				"driver": {
+					"name": "fluxo",
+					"version": env!("CARGO_PKG_VERSION"),
+					"informationUri": env!("CARGO_PKG_HOMEPAGE"),
+					"rules": rules.into_values().collect::<Vec<_>>(),
+				}
+			},
+			"results": results,
+		}]
+	});
+
+	Ok(serde_json::to_string_pretty(&sarif)?)
+}
+
+/// One test suite per file and one test case per diagnostic,
+/// diagnostics below `fail_on` severity are reported as passed
+pub fn to_junit(report: &Report, fail_on: Severity) -> String {
+	let mut suites: BTreeMap<String, Vec<&Diagnostic>> = BTreeMap::new();
+
+	for diagnostic in &report.diagnostics {
+		let name = diagnostic
+			.path
+			.as_deref()
+			.map(to_uri)
+			.unwrap_or(String::from("project"));
+		suites.entry(name).or_default().push(diagnostic);
+	}
+
+	let failures = report.count_at_least(fail_on);
+	let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+	xml.push_str(&format!(
+		"<testsuites name=\"fluxo validate\" tests=\"{}\" failures=\"{}\">\n",
+		report.diagnostics.len().max(1),
+		failures
+	));
+
+	if suites.is_empty() {
+		xml.push_str("\t<testsuite name=\"fluxo\" tests=\"1\" failures=\"0\">\n");
+		xml.push_str("\t\t<testcase classname=\"fluxo\" name=\"validation\"/>\n");
+		xml.push_str("\t</testsuite>\n");
+	}
+
+	for (name, diagnostics) in suites {
+		let failures = diagnostics.iter().filter(|d| d.severity >= fail_on).count();
+
+		xml.push_str(&format!(
+			"\t<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+			escape_xml(&name),
+			diagnostics.len(),
+			failures
+		));
+
+		for diagnostic in diagnostics {
+			let case_name = diagnostic.location().unwrap_or(diagnostic.rule.clone());
+
+			xml.push_str(&format!(
+				"\t\t<testcase classname=\"{}\" name=\"{}\">\n",
+				escape_xml(&diagnostic.rule),
+				escape_xml(&case_name)
+			));
+
+			let body = match &diagnostic.snippet {
+				Some(snippet) => format!("{}\n{}", diagnostic, snippet),
+				None => diagnostic.to_string(),
+			};
+
+			if diagnostic.severity >= fail_on {
+				xml.push_str(&format!(
+					"\t\t\t<failure message=\"{}\" type=\"{}\">{}</failure>\n",
+					escape_xml(&diagnostic.message),
+					diagnostic.severity,
+					escape_xml(&body)
+				));
+			} else {
+				xml.push_str(&format!("\t\t\t<system-out>{}</system-out>\n", escape_xml(&body)));
+			}
+
+			xml.push_str("\t\t</testcase>\n");
+		}
+
+		xml.push_str("\t</testsuite>\n");
+	}
+
+	xml.push_str("</testsuites>\n");
+	xml
+}
+
+fn to_uri(path: &Path) -> String {
+	path.to_string_lossy().replace('\\', "/")
+}
+
+fn escape_xml(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+
+	for char in text.chars() {
+		match char {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&apos;"),
+			_ => escaped.push(char),
+		}
+	}
+
+	escaped
+}
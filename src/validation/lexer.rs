@@ -0,0 +1,317 @@
+const SYMBOLS: [&str; 17] = [
+	"...", "..=", "//=", "..", "::", "->", "==", "~=", "<=", ">=", "+=", "-=", "*=", "/=", "//", "%=", "^=",
+];
+
+const KEYWORDS: [&str; 22] = [
+	"and", "break", "continue", "do", "else", "elseif", "end", "false", "for", "function", "if", "in", "local", "nil",
+	"not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+	Name,
+	Keyword,
+	Number,
+	String,
+	/// Literal segment of an interpolated (backtick) string
+	InterpolatedString,
+	Symbol,
+	Comment,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+	pub kind: TokenKind,
+	pub value: String,
+	/// 1-based line number
+	pub line: usize,
+	/// 1-based column number, counted in characters
+	pub column: usize,
+}
+
+impl Token {
+	pub fn is_name(&self, name: &str) -> bool {
+		self.kind == TokenKind::Name && self.value == name
+	}
+
+	pub fn is_keyword(&self, keyword: &str) -> bool {
+		self.kind == TokenKind::Keyword && self.value == keyword
+	}
+
+	pub fn is_symbol(&self, symbol: &str) -> bool {
+		self.kind == TokenKind::Symbol && self.value == symbol
+	}
+
+	pub fn is_string(&self) -> bool {
+		self.kind == TokenKind::String
+	}
+
+	pub fn is_comment(&self) -> bool {
+		self.kind == TokenKind::Comment
+	}
+}
+
+/// Split Luau source into tokens, including comments.
+/// Invalid input never fails, unterminated strings
+/// and comments simply end at the end of the source
+pub fn tokenize(source: &str) -> Vec<Token> {
+	let mut lexer = Lexer {
+		chars: source.chars().collect(),
+		pos: 0,
+		line: 1,
+		column: 1,
+		interpolations: Vec::new(),
+		tokens: Vec::new(),
+	};
+
+	lexer.run();
+	lexer.tokens
+}
+
+/// Same as `tokenize` but without comments
+pub fn tokenize_code(source: &str) -> Vec<Token> {
+	tokenize(source)
+		.into_iter()
+		.filter(|token| !token.is_comment())
+		.collect()
+}
+
+struct Lexer {
+	chars: Vec<char>,
+	pos: usize,
+	line: usize,
+	column: usize,
+	/// Brace depth of every interpolated string expression we are currently in
+	interpolations: Vec<usize>,
+	tokens: Vec<Token>,
+}
+
+impl Lexer {
+	fn run(&mut self) {
+		while let Some(char) = self.peek(0) {
+			let (line, column) = (self.line, self.column);
+
+			match char {
+				char if char.is_whitespace() => {
+					self.advance();
+				}
+				'-' if self.peek(1) == Some('-') => {
+					self.advance_by(2);
+
+					let value = if let Some(level) = self.long_bracket_level() {
+						self.read_long_bracket(level)
+					} else {
+						self.read_while(|char| char != '\n')
+					};
+
+					self.push(TokenKind::Comment, value, line, column);
+				}
+				'[' if self.long_bracket_level().is_some() => {
+					let level = self.long_bracket_level().unwrap();
+					let value = self.read_long_bracket(level);
+
+					self.push(TokenKind::String, value, line, column);
+				}
+				'"' | '\'' => {
+					self.advance();
+
+					let value = self.read_quoted(char);
+
+					self.push(TokenKind::String, value, line, column);
+				}
+				'`' => {
+					self.advance();
+					self.read_interpolated(line, column);
+				}
+				char if char.is_ascii_digit() || (char == '.' && self.peek(1).is_some_and(|c| c.is_ascii_digit())) => {
+					let value = self.read_number();
+
+					self.push(TokenKind::Number, value, line, column);
+				}
+				char if char.is_alphabetic() || char == '_' => {
+					let value = self.read_while(|char| char.is_alphanumeric() || char == '_');
+					let kind = if KEYWORDS.contains(&value.as_str()) {
+						TokenKind::Keyword
+					} else {
+						TokenKind::Name
+					};
+
+					self.push(kind, value, line, column);
+				}
+				'}' if self.interpolations.last() == Some(&0) => {
+					self.advance();
+					self.interpolations.pop();
+					self.read_interpolated(line, column);
+				}
+				_ => {
+					let symbol = SYMBOLS
+						.iter()
+						.find(|symbol| symbol.chars().enumerate().all(|(i, c)| self.peek(i) == Some(c)))
+						.map(|symbol| symbol.to_string())
+						.unwrap_or(char.to_string());
+
+					if let Some(depth) = self.interpolations.last_mut() {
+						match char {
+							'{' => *depth += 1,
+							'}' => *depth -= 1,
+							_ => {}
+						}
+					}
+
+					self.advance_by(symbol.chars().count());
+					self.push(TokenKind::Symbol, symbol, line, column);
+				}
+			}
+		}
+	}
+
+	fn push(&mut self, kind: TokenKind, value: String, line: usize, column: usize) {
+		self.tokens.push(Token {
+			kind,
+			value,
+			line,
+			column,
+		});
+	}
+
+	fn peek(&self, offset: usize) -> Option<char> {
+		self.chars.get(self.pos + offset).copied()
+	}
+
+	fn advance(&mut self) -> Option<char> {
+		let char = self.peek(0)?;
+
+		self.pos += 1;
+
+		if char == '\n' {
+			self.line += 1;
+			self.column = 1;
+		} else {
+			self.column += 1;
+		}
+
+		Some(char)
+	}
+
+	fn advance_by(&mut self, count: usize) {
+		for _ in 0..count {
+			self.advance();
+		}
+	}
+
+	fn read_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+		let mut value = String::new();
+
+		while let Some(char) = self.peek(0) {
+			if !predicate(char) {
+				break;
+			}
+
+			value.push(char);
+			self.advance();
+		}
+
+		value
+	}
+
+	/// Returns the level of the long bracket (`[[`, `[==[`) at the current position
+	fn long_bracket_level(&self) -> Option<usize> {
+		if self.peek(0) != Some('[') {
+			return None;
+		}
+
+		let mut level = 0;
+
+		while self.peek(level + 1) == Some('=') {
+			level += 1;
+		}
+
+		if self.peek(level + 1) == Some('[') {
+			Some(level)
+		} else {
+			None
+		}
+	}
+
+	fn read_long_bracket(&mut self, level: usize) -> String {
+		let mut value = String::new();
+
+		self.advance_by(level + 2);
+
+		while let Some(char) = self.peek(0) {
+			if char == ']' && (1..=level).all(|i| self.peek(i) == Some('=')) && self.peek(level + 1) == Some(']') {
+				self.advance_by(level + 2);
+				break;
+			}
+
+			value.push(char);
+			self.advance();
+		}
+
+		value
+	}
+
+	fn read_quoted(&mut self, quote: char) -> String {
+		let mut value = String::new();
+
+		while let Some(char) = self.advance() {
+			match char {
+				'\\' => {
+					value.push(char);
+
+					if let Some(char) = self.advance() {
+						value.push(char);
+					}
+				}
+				'\n' => break,
+				char if char == quote => break,
+				_ => value.push(char),
+			}
+		}
+
+		value
+	}
+
+	/// Reads literal part of the interpolated string until
+	/// its end or the beginning of the next expression
+	fn read_interpolated(&mut self, line: usize, column: usize) {
+		let mut value = String::new();
+
+		while let Some(char) = self.advance() {
+			match char {
+				'\\' => {
+					value.push(char);
+
+					if let Some(char) = self.advance() {
+						value.push(char);
+					}
+				}
+				'{' => {
+					self.interpolations.push(0);
+					break;
+				}
+				'`' => break,
+				_ => value.push(char),
+			}
+		}
+
+		self.push(TokenKind::InterpolatedString, value, line, column);
+	}
+
+	fn read_number(&mut self) -> String {
+		let mut value = String::new();
+
+		while let Some(char) = self.peek(0) {
+			let is_exponent = matches!(value.chars().last(), Some('e' | 'E')) && !value.starts_with("0x");
+
+			if char.is_alphanumeric() || char == '_' || char == '.' || (is_exponent && (char == '+' || char == '-')) {
+				value.push(char);
+				self.advance();
+			} else {
+				break;
+			}
+		}
+
+		value
+	}
+}
@@ -0,0 +1,145 @@
+use clap::ValueEnum;
+use rbx_dom_weak::{types::Ref, WeakDom};
+use serde::{Deserialize, Serialize};
+use std::{
+	fmt::{self, Display, Formatter},
+	path::{Path, PathBuf},
+};
+
+pub mod banned_apis;
+pub mod budgets;
+pub mod cache;
+pub mod checks;
+pub mod format;
+pub mod lexer;
+pub mod properties;
+pub mod report;
+pub mod requires;
+pub mod rules;
+pub mod suppression;
+pub mod validator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+	Info,
+	Warning,
+	Error,
+}
+
+impl Display for Severity {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}",
+			match self {
+				Self::Info => "info",
+				Self::Warning => "warning",
+				Self::Error => "error",
+			}
+		)
+	}
+}
+
+/// Single validation finding, optionally pointing at the exact place in a file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+	pub rule: String,
+	pub severity: Severity,
+	pub message: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub path: Option<PathBuf>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub line: Option<usize>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub column: Option<usize>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub snippet: Option<String>,
+}
+
+impl Diagnostic {
+	pub fn new(rule: &str, severity: Severity, message: impl Into<String>) -> Self {
+		Self {
+			rule: rule.to_owned(),
+			severity,
+			message: message.into(),
+			path: None,
+			line: None,
+			column: None,
+			snippet: None,
+		}
+	}
+
+	pub fn with_path(mut self, path: &Path) -> Self {
+		self.path = Some(path.to_owned());
+		self
+	}
+
+	pub fn with_location(mut self, line: usize, column: usize) -> Self {
+		self.line = Some(line);
+		self.column = Some(column);
+		self
+	}
+
+	pub fn with_snippet(mut self, snippet: &str) -> Self {
+		self.snippet = Some(snippet.to_owned());
+		self
+	}
+
+	/// Make the path relative to the given `base` directory
+	pub fn relative_to(mut self, base: &Path) -> Self {
+		if let Some(path) = &self.path {
+			if let Ok(relative) = path.strip_prefix(base) {
+				self.path = Some(relative.to_owned());
+			}
+		}
+
+		self
+	}
+
+	/// Returns `path:line:column` or as much of it as is known
+	pub fn location(&self) -> Option<String> {
+		let path = self.path.as_ref()?.display();
+
+		Some(match (self.line, self.column) {
+			(Some(line), Some(column)) => format!("{}:{}:{}", path, line, column),
+			(Some(line), None) => format!("{}:{}", path, line),
+			_ => path.to_string(),
+		})
+	}
+}
+
+impl Display for Diagnostic {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		if let Some(location) = self.location() {
+			write!(f, "{}: ", location)?;
+		}
+
+		write!(f, "{} [{}]", self.message, self.rule)
+	}
+}
+
+/// Returns trimmed contents of the given 1-based `line`
+pub fn line_snippet(source: &str, line: usize) -> &str {
+	source.lines().nth(line.saturating_sub(1)).unwrap_or_default().trim()
+}
+
+/// Returns dot separated names of the instance and all of its ancestors
+pub fn full_name(dom: &WeakDom, id: Ref) -> String {
+	let mut names = Vec::new();
+	let mut current = dom.get_by_ref(id);
+
+	while let Some(instance) = current {
+		// Like `GetFullName`, the DataModel itself is not included
+		if instance.class == "DataModel" {
+			break;
+		}
+
+		names.push(instance.name.as_str());
+		current = dom.get_by_ref(instance.parent());
+	}
+
+	names.reverse();
+	names.join(".")
+}
@@ -0,0 +1,302 @@
+use anyhow::Result;
+use rbx_reflection::{
+	ClassDescriptor, ClassTag, PropertyDescriptor, PropertyKind, PropertySerialization, PropertyTag, Scriptability,
+};
+use serde::Deserialize;
+use std::{
+	collections::{BTreeMap, HashSet},
+	fs,
+	path::{Path, PathBuf},
+};
+
+use super::{line_snippet, Diagnostic, Severity};
+use crate::{
+	core::{
+		meta::{SourceEntry, SourceKind},
+		tree::Tree,
+	},
+	ext::ResultExt,
+	resolution::UnresolvedValue,
+};
+
+pub const UNKNOWN_CLASS_RULE: &str = "unknown-class";
+pub const UNKNOWN_PROPERTY_RULE: &str = "unknown-property";
+pub const DEPRECATED_RULE: &str = "deprecated-member";
+pub const NOT_SCRIPTABLE_RULE: &str = "not-scriptable";
+pub const TYPE_MISMATCH_RULE: &str = "property-type";
+
+/// Instance data read from `.meta.json` and `.model.json` files
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Data {
+	#[serde(alias = "ClassName")]
+	class_name: Option<String>,
+	#[serde(alias = "Properties", default)]
+	properties: BTreeMap<String, UnresolvedValue>,
+	#[serde(alias = "Attributes")]
+	attributes: Option<UnresolvedValue>,
+	#[serde(alias = "Children", default)]
+	children: Vec<Data>,
+}
+
+/// Check that `class` exists and is not deprecated
+pub fn check_class(class: &str) -> Vec<Diagnostic> {
+	let database = rbx_reflection_database::get();
+
+	match database.classes.get(class) {
+		Some(descriptor) if descriptor.tags.contains(&ClassTag::Deprecated) => vec![Diagnostic::new(
+			DEPRECATED_RULE,
+			Severity::Warning,
+			format!("Class {} is deprecated", class),
+		)],
+		Some(_) => vec![],
+		None => {
+			let suggestion = suggest(class, database.classes.keys().map(|name| name.as_ref()));
+
+			vec![Diagnostic::new(
+				UNKNOWN_CLASS_RULE,
+				Severity::Error,
+				format!("Unknown class {}{}", class, did_you_mean(suggestion)),
+			)]
+		}
+	}
+}
+
+/// Check `property` set on an instance of `class`, unknown classes are skipped
+pub fn check_property(class: &str, property: &str, value: &UnresolvedValue) -> Vec<Diagnostic> {
+	let mut diagnostics = Vec::new();
+
+	if !rbx_reflection_database::get().classes.contains_key(class) {
+		return diagnostics;
+	}
+
+	let Some(descriptor) = find_descriptor(class, property) else {
+		let suggestion = suggest(property, property_names(class).into_iter());
+
+		diagnostics.push(Diagnostic::new(
+			UNKNOWN_PROPERTY_RULE,
+			Severity::Error,
+			format!("Unknown property {}.{}{}", class, property, did_you_mean(suggestion)),
+		));
+
+		return diagnostics;
+	};
+
+	if descriptor.tags.contains(&PropertyTag::Deprecated) {
+		let replacement = match &descriptor.kind {
+			PropertyKind::Alias { alias_for } => format!(", use {} instead", alias_for),
+			PropertyKind::Canonical {
+				serialization: PropertySerialization::Migrate(migration),
+			} => format!(", use {} instead", migration.new_property_name),
+			_ => String::new(),
+		};
+
+		diagnostics.push(Diagnostic::new(
+			DEPRECATED_RULE,
+			Severity::Warning,
+			format!("Property {}.{} is deprecated{}", class, property, replacement),
+		));
+	}
+
+	if matches!(descriptor.scriptability, Scriptability::None | Scriptability::Read) {
+		diagnostics.push(Diagnostic::new(
+			NOT_SCRIPTABLE_RULE,
+			Severity::Warning,
+			format!(
+				"Property {}.{} cannot be written by scripts, it will not sync to Studio",
+				class, property
+			),
+		));
+	}
+
+	if let Err(err) = value.clone().resolve(class, property) {
+		diagnostics.push(Diagnostic::new(TYPE_MISMATCH_RULE, Severity::Error, err.to_string()));
+	}
+
+	diagnostics
+}
+
+/// Check project nodes, `.meta.json` and `.model.json` files
+/// of all instances in the built `tree`
+pub fn check_tree(tree: &Tree, workspace_dir: &Path) -> Result<Vec<Diagnostic>> {
+	let mut diagnostics = Vec::new();
+	let mut visited = HashSet::new();
+
+	let dom = tree.inner();
+
+	for instance in dom.descendants() {
+		let Some(meta) = tree.get_meta(instance.referent()) else {
+			continue;
+		};
+
+		let class = instance.class.as_str();
+
+		if let SourceKind::Project(_, path, node, node_path) = meta.source.get() {
+			let contents = fs::read_to_string(path)?;
+			let relative = path.strip_prefix(workspace_dir).unwrap_or(path);
+			let anchor = node_path.iter().last().map(|name| name.as_str());
+
+			let mut found = Vec::new();
+
+			if let Some(class_name) = &node.class_name {
+				found.extend(
+					check_class(class_name)
+						.into_iter()
+						.map(|d| locate(d, &contents, anchor, class_name)),
+				);
+			}
+
+			for (property, value) in &node.properties {
+				found.extend(
+					check_property(class, property, value)
+						.into_iter()
+						.map(|d| locate(d, &contents, anchor, property)),
+				);
+			}
+
+			if let Some(attributes) = &node.attributes {
+				found.extend(check_attributes(class, attributes).map(|d| locate(d, &contents, anchor, "$attributes")));
+			}
+
+			diagnostics.extend(found.into_iter().map(|d| d.with_path(relative)));
+		}
+
+		if let Some(SourceEntry::Data(path)) = meta.source.get_data() {
+			if visited.insert(path.clone()) {
+				diagnostics.extend(check_file(path, workspace_dir, Some(class))?);
+			}
+		}
+
+		for entry in meta.source.relevant() {
+			if let SourceEntry::File(path) = entry {
+				if path.to_string_lossy().ends_with(".model.json") && visited.insert(path.clone()) {
+					diagnostics.extend(check_file(path, workspace_dir, None)?);
+				}
+			}
+		}
+	}
+
+	Ok(diagnostics)
+}
+
+/// Check a `.meta.json` file of instance of `class`,
+/// or a `.model.json` file when `class` is `None`
+fn check_file(path: &PathBuf, workspace_dir: &Path, class: Option<&str>) -> Result<Vec<Diagnostic>> {
+	let contents = fs::read_to_string(path)?;
+	let relative = path.strip_prefix(workspace_dir).unwrap_or(path);
+
+	if contents.trim().is_empty() {
+		return Ok(vec![]);
+	}
+
+	let data: Data = serde_json::from_str(&contents).with_desc(|| format!("Failed to parse {}", relative.display()))?;
+
+	let mut diagnostics = Vec::new();
+	check_data(&data, class, &contents, &mut diagnostics);
+
+	Ok(diagnostics.into_iter().map(|d| d.with_path(relative)).collect())
+}
+
+fn check_data(data: &Data, class: Option<&str>, contents: &str, diagnostics: &mut Vec<Diagnostic>) {
+	let class = match (&data.class_name, class) {
+		(Some(class_name), _) => {
+			diagnostics.extend(
+				check_class(class_name)
+					.into_iter()
+					.map(|d| locate(d, contents, None, class_name)),
+			);
+			class_name.as_str()
+		}
+		(None, Some(class)) => class,
+		(None, None) => "Folder",
+	};
+
+	for (property, value) in &data.properties {
+		diagnostics.extend(
+			check_property(class, property, value)
+				.into_iter()
+				.map(|d| locate(d, contents, None, property)),
+		);
+	}
+
+	if let Some(attributes) = &data.attributes {
+		diagnostics.extend(check_attributes(class, attributes).map(|d| locate(d, contents, None, "attributes")));
+	}
+
+	for child in &data.children {
+		check_data(child, None, contents, diagnostics);
+	}
+}
+
+fn check_attributes(class: &str, attributes: &UnresolvedValue) -> Option<Diagnostic> {
+	if !rbx_reflection_database::get().classes.contains_key(class) {
+		return None;
+	}
+
+	attributes
+		.clone()
+		.resolve(class, "Attributes")
+		.err()
+		.map(|err| Diagnostic::new(TYPE_MISMATCH_RULE, Severity::Error, err.to_string()))
+}
+
+fn find_descriptor(class: &str, property: &str) -> Option<&'static PropertyDescriptor<'static>> {
+	ancestry(class).find_map(|class| class.properties.get(property))
+}
+
+fn property_names(class: &str) -> Vec<&'static str> {
+	ancestry(class)
+		.flat_map(|class| class.properties.keys().map(|name| name.as_ref()))
+		.collect()
+}
+
+/// Returns `class` followed by all of its superclasses
+fn ancestry(class: &str) -> impl Iterator<Item = &'static ClassDescriptor<'static>> {
+	let database = rbx_reflection_database::get();
+
+	std::iter::successors(database.classes.get(class), |class| {
+		database.classes.get(class.superclass.as_deref()?)
+	})
+}
+
+/// Returns the closest known name, if it is similar enough
+fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+	let lowercase = name.to_lowercase();
+	let max_distance = (name.len() / 3).max(2);
+
+	candidates
+		.map(|candidate| {
+			(
+				strsim::damerau_levenshtein(&lowercase, &candidate.to_lowercase()),
+				candidate,
+			)
+		})
+		.filter(|(distance, _)| *distance <= max_distance)
+		.min()
+		.map(|(_, candidate)| candidate)
+}
+
+fn did_you_mean(suggestion: Option<&str>) -> String {
+	suggestion
+		.map(|suggestion| format!(", did you mean {}?", suggestion))
+		.unwrap_or_default()
+}
+
+/// Set location of the first `"key"` that appears after the `anchor` key
+fn locate(diagnostic: Diagnostic, contents: &str, anchor: Option<&str>, key: &str) -> Diagnostic {
+	let start = anchor
+		.and_then(|anchor| contents.find(&format!("\"{}\"", anchor)))
+		.unwrap_or(0);
+
+	let Some(offset) = contents[start..].find(&format!("\"{}\"", key)) else {
+		return diagnostic;
+	};
+
+	let before = &contents[..start + offset];
+	let line = before.matches('\n').count() + 1;
+	let column = before.len() - before.rfind('\n').map(|index| index + 1).unwrap_or(0) + 1;
+
+	diagnostic
+		.with_location(line, column)
+		.with_snippet(line_snippet(contents, line))
+}
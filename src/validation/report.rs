@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Diagnostic, Severity};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Summary {
+	pub errors: usize,
+	pub warnings: usize,
+	pub infos: usize,
+}
+
+/// Result of a validation run, shared by every output format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+	pub success: bool,
+	pub summary: Summary,
+	pub diagnostics: Vec<Diagnostic>,
+	pub timestamp: String,
+}
+
+impl Report {
+	pub fn new(mut diagnostics: Vec<Diagnostic>) -> Self {
+		diagnostics.sort_by(|a, b| {
+			b.severity
+				.cmp(&a.severity)
+				.then_with(|| a.path.cmp(&b.path))
+				.then_with(|| a.line.cmp(&b.line))
+				.then_with(|| a.column.cmp(&b.column))
+		});
+
+		let count = |severity| diagnostics.iter().filter(|d| d.severity == severity).count();
+
+		let summary = Summary {
+			errors: count(Severity::Error),
+			warnings: count(Severity::Warning),
+			infos: count(Severity::Info),
+		};
+
+		Self {
+			success: summary.errors == 0,
+			summary,
+			diagnostics,
+			timestamp: chrono::Utc::now().to_rfc3339(),
+		}
+	}
+
+	/// Returns all diagnostics with the exact `severity`
+	pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &Diagnostic> {
+		self.diagnostics.iter().filter(move |d| d.severity == severity)
+	}
+
+	/// Returns number of diagnostics with `severity` or higher
+	pub fn count_at_least(&self, severity: Severity) -> usize {
+		self.diagnostics.iter().filter(|d| d.severity >= severity).count()
+	}
+}
@@ -0,0 +1,601 @@
+use rbx_dom_weak::{
+	types::{Ref, Variant},
+	ustr, WeakDom,
+};
+use std::{
+	collections::{HashMap, HashSet},
+	fmt::Write,
+	path::{Path, PathBuf},
+};
+
+use super::{
+	full_name,
+	lexer::{self, Token, TokenKind},
+	line_snippet, Diagnostic, Severity,
+};
+use crate::{
+	core::{meta::SourceEntry, tree::Tree},
+	util,
+};
+
+pub const UNRESOLVED_RULE: &str = "unresolved-require";
+pub const NON_MODULE_RULE: &str = "require-non-module";
+pub const CYCLE_RULE: &str = "require-cycle";
+
+/// Script or module that takes part in the require graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+	/// Full name of the instance, like `Plugin.Modules.Util`
+	pub name: String,
+	pub class: String,
+	pub path: Option<PathBuf>,
+}
+
+/// `from` requires `to`, both are indices into `RequireGraph::nodes`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Edge {
+	pub from: usize,
+	pub to: usize,
+	pub line: usize,
+	pub column: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequireGraph {
+	pub nodes: Vec<Node>,
+	pub edges: Vec<Edge>,
+}
+
+impl RequireGraph {
+	/// Render the graph in Graphviz DOT format
+	pub fn to_dot(&self) -> String {
+		let mut dot = String::from("digraph requires {\n\trankdir=LR;\n\tnode [shape=box];\n");
+
+		for (index, node) in self.nodes.iter().enumerate() {
+			let style = if node.class == "ModuleScript" {
+				""
+			} else {
+				", style=bold"
+			};
+
+			writeln!(
+				dot,
+				"\tn{} [label=\"{}\"{}];",
+				index,
+				node.name.replace('"', "\\\""),
+				style
+			)
+			.unwrap();
+		}
+
+		for edge in self.unique_edges() {
+			writeln!(dot, "\tn{} -> n{};", edge.0, edge.1).unwrap();
+		}
+
+		dot.push_str("}\n");
+		dot
+	}
+
+	/// Render the graph as a Mermaid flowchart
+	pub fn to_mermaid(&self) -> String {
+		let mut mermaid = String::from("graph LR\n");
+
+		for (index, node) in self.nodes.iter().enumerate() {
+			writeln!(mermaid, "\tn{}[\"{}\"]", index, node.name.replace('"', "#quot;")).unwrap();
+		}
+
+		for edge in self.unique_edges() {
+			writeln!(mermaid, "\tn{} --> n{}", edge.0, edge.1).unwrap();
+		}
+
+		mermaid
+	}
+
+	fn unique_edges(&self) -> Vec<(usize, usize)> {
+		let mut edges = Vec::new();
+
+		for edge in &self.edges {
+			if !edges.contains(&(edge.from, edge.to)) {
+				edges.push((edge.from, edge.to));
+			}
+		}
+
+		edges
+	}
+}
+
+/// Result of the require analysis
+#[derive(Debug, Clone, Default)]
+pub struct Analysis {
+	pub graph: RequireGraph,
+	pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Analyze requires of all scripts in the built `tree`
+pub fn analyze(tree: &Tree, workspace_dir: &Path) -> Analysis {
+	analyze_dom(tree.inner(), |id| {
+		tree.get_meta(id)?
+			.source
+			.relevant()
+			.iter()
+			.find_map(|entry| match entry {
+				SourceEntry::File(path) => Some(path.strip_prefix(workspace_dir).unwrap_or(path).to_owned()),
+				_ => None,
+			})
+	})
+}
+
+/// Analyze requires of all scripts in the `dom`, `path_of`
+/// returns the source file of the given instance if it has one
+pub fn analyze_dom(dom: &WeakDom, path_of: impl Fn(Ref) -> Option<PathBuf>) -> Analysis {
+	let mut analysis = Analysis::default();
+	let mut indices = HashMap::new();
+
+	let scripts: Vec<Ref> = dom
+		.descendants()
+		.filter(|instance| util::is_script(&instance.class))
+		.map(|instance| instance.referent())
+		.collect();
+
+	for &id in &scripts {
+		let instance = dom.get_by_ref(id).unwrap();
+
+		indices.insert(id, analysis.graph.nodes.len());
+		analysis.graph.nodes.push(Node {
+			name: full_name(dom, id),
+			class: instance.class.to_string(),
+			path: path_of(id),
+		});
+	}
+
+	for &id in &scripts {
+		let instance = dom.get_by_ref(id).unwrap();
+		let from = indices[&id];
+
+		let source = match instance.properties.get(&ustr("Source")) {
+			Some(Variant::String(source)) => source,
+			_ => continue,
+		};
+
+		let diagnostic = |rule: &str, message: String, line: usize, column: usize| {
+			let mut diagnostic = Diagnostic::new(rule, Severity::Error, message)
+				.with_location(line, column)
+				.with_snippet(line_snippet(source, line));
+
+			if let Some(path) = &analysis.graph.nodes[from].path {
+				diagnostic = diagnostic.with_path(path);
+			}
+
+			diagnostic
+		};
+
+		let mut edges = Vec::new();
+		let mut diagnostics = Vec::new();
+
+		for (line, column, expression) in find_requires(source) {
+			let target = match resolve(dom, id, &expression) {
+				Resolution::Found(target) => target,
+				Resolution::Missing(reason) => {
+					diagnostics.push(diagnostic(
+						UNRESOLVED_RULE,
+						format!("Cannot resolve require of {}: {}", expression.text, reason),
+						line,
+						column,
+					));
+					continue;
+				}
+				Resolution::Dynamic => continue,
+			};
+
+			let target_instance = dom.get_by_ref(target).unwrap();
+
+			if target_instance.class != "ModuleScript" {
+				diagnostics.push(diagnostic(
+					NON_MODULE_RULE,
+					format!(
+						"Required instance '{}' is a {}, only ModuleScripts can be required",
+						full_name(dom, target),
+						target_instance.class
+					),
+					line,
+					column,
+				));
+				continue;
+			}
+
+			edges.push(Edge {
+				from,
+				to: indices[&target],
+				line,
+				column,
+			});
+		}
+
+		analysis.graph.edges.extend(edges);
+		analysis.diagnostics.extend(diagnostics);
+	}
+
+	for cycle in find_cycles(&analysis.graph) {
+		let closing = cycle.last().unwrap();
+		let from = &analysis.graph.nodes[closing.from];
+
+		let names: Vec<&str> = cycle
+			.iter()
+			.map(|edge| analysis.graph.nodes[edge.from].name.as_str())
+			.chain([analysis.graph.nodes[closing.to].name.as_str()])
+			.collect();
+
+		let mut diagnostic = Diagnostic::new(
+			CYCLE_RULE,
+			Severity::Error,
+			format!("Require cycle: {}", names.join(" -> ")),
+		)
+		.with_location(closing.line, closing.column);
+
+		if let Some(path) = &from.path {
+			diagnostic = diagnostic.with_path(path);
+		}
+
+		analysis.diagnostics.push(diagnostic);
+	}
+
+	analysis
+}
+
+/// Argument of the `require` call
+#[derive(Debug, Clone, PartialEq)]
+struct Expression {
+	root: Root,
+	steps: Vec<Step>,
+	/// Source text of the expression used in diagnostics
+	text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Root {
+	Script,
+	Game,
+	Dynamic,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+	Parent,
+	Child(String),
+	Service(String),
+}
+
+enum Resolution {
+	Found(Ref),
+	Missing(String),
+	/// Depends on runtime values or on instances outside of the project
+	Dynamic,
+}
+
+/// Returns line, column and parsed argument of every `require` call
+fn find_requires(source: &str) -> Vec<(usize, usize, Expression)> {
+	let tokens = lexer::tokenize_code(source);
+	let mut requires = Vec::new();
+
+	for (index, token) in tokens.iter().enumerate() {
+		if !token.is_name("require") || !tokens.get(index + 1).is_some_and(|t| t.is_symbol("(")) {
+			continue;
+		}
+
+		if index > 0 && (tokens[index - 1].is_symbol(".") || tokens[index - 1].is_symbol(":")) {
+			continue;
+		}
+
+		let mut depth = 0;
+		let mut end = None;
+
+		for (offset, token) in tokens[index + 1..].iter().enumerate() {
+			if token.is_symbol("(") {
+				depth += 1;
+			} else if token.is_symbol(")") {
+				depth -= 1;
+
+				if depth == 0 {
+					end = Some(index + 1 + offset);
+					break;
+				}
+			}
+		}
+
+		if let Some(end) = end {
+			requires.push((token.line, token.column, parse_expression(&tokens[index + 2..end])));
+		}
+	}
+
+	requires
+}
+
+fn parse_expression(tokens: &[Token]) -> Expression {
+	let text = tokens
+		.iter()
+		.map(|token| {
+			if token.is_string() {
+				format!("\"{}\"", token.value)
+			} else {
+				token.value.clone()
+			}
+		})
+		.collect::<String>();
+
+	let dynamic = Expression {
+		root: Root::Dynamic,
+		steps: Vec::new(),
+		text: text.clone(),
+	};
+
+	let Some(first) = tokens.first() else {
+		return dynamic;
+	};
+
+	if first.is_string() && tokens.len() == 1 {
+		return parse_string_require(&first.value, text).unwrap_or(dynamic);
+	}
+
+	let root = if first.is_name("script") {
+		Root::Script
+	} else if first.is_name("game") {
+		Root::Game
+	} else {
+		return dynamic;
+	};
+
+	let mut steps = Vec::new();
+	let mut index = 1;
+
+	let get = |index: usize| tokens.get(index);
+	let is_string = |index: usize| get(index).is_some_and(|t| t.is_string());
+
+	while index < tokens.len() {
+		let token = &tokens[index];
+
+		if token.is_symbol(".") && get(index + 1).is_some_and(|t| t.kind == TokenKind::Name) {
+			let name = &tokens[index + 1].value;
+
+			steps.push(if name == "Parent" {
+				Step::Parent
+			} else {
+				Step::Child(name.clone())
+			});
+
+			index += 2;
+		} else if token.is_symbol("[") && is_string(index + 1) && get(index + 2).is_some_and(|t| t.is_symbol("]")) {
+			steps.push(Step::Child(tokens[index + 1].value.clone()));
+			index += 3;
+		} else if token.is_symbol(":") && get(index + 2).is_some_and(|t| t.is_symbol("(")) && is_string(index + 3) {
+			let method = &tokens[index + 1];
+			let argument = tokens[index + 3].value.clone();
+
+			// Skip optional arguments like `WaitForChild` timeout
+			let Some(close) = tokens[index + 4..].iter().position(|t| t.is_symbol(")")) else {
+				return dynamic;
+			};
+
+			if method.is_name("FindFirstChild") || method.is_name("WaitForChild") {
+				steps.push(Step::Child(argument));
+			} else if method.is_name("GetService") {
+				steps.push(Step::Service(argument));
+			} else {
+				return dynamic;
+			}
+
+			index += 5 + close;
+		} else {
+			return dynamic;
+		}
+	}
+
+	Expression { root, steps, text }
+}
+
+/// Luau require-by-string paths, relative to the requiring script
+fn parse_string_require(path: &str, text: String) -> Option<Expression> {
+	let (mut steps, rest) = if let Some(rest) = path.strip_prefix("@self/") {
+		(vec![], rest)
+	} else if let Some(rest) = path.strip_prefix("./") {
+		(vec![Step::Parent], rest)
+	} else if path.starts_with("../") {
+		(vec![Step::Parent], path)
+	} else {
+		return None;
+	};
+
+	for segment in rest.split('/') {
+		match segment {
+			"" | "." => {}
+			".." => steps.push(Step::Parent),
+			_ => steps.push(Step::Child(segment.to_owned())),
+		}
+	}
+
+	Some(Expression {
+		root: Root::Script,
+		steps,
+		text,
+	})
+}
+
+fn resolve(dom: &WeakDom, script: Ref, expression: &Expression) -> Resolution {
+	let mut current = match expression.root {
+		Root::Script => script,
+		// Services are only known when building a place
+		Root::Game if dom.root().class == "DataModel" => dom.root_ref(),
+		_ => return Resolution::Dynamic,
+	};
+
+	for step in &expression.steps {
+		let instance = dom.get_by_ref(current).unwrap();
+
+		current = match step {
+			Step::Parent => {
+				if current == dom.root_ref() {
+					return Resolution::Missing(format!("'{}' has no parent", full_name(dom, current)));
+				}
+
+				instance.parent()
+			}
+			Step::Child(name) => {
+				let child = instance
+					.children()
+					.iter()
+					.find(|&&child| dom.get_by_ref(child).unwrap().name == *name);
+
+				match child {
+					Some(child) => *child,
+					None => {
+						return Resolution::Missing(format!(
+							"'{}' has no child named '{}'",
+							full_name(dom, current),
+							name
+						))
+					}
+				}
+			}
+			Step::Service(class) => {
+				let service = instance.children().iter().find(|&&child| {
+					let child = dom.get_by_ref(child).unwrap();
+					child.class == class.as_str() || child.name == *class
+				});
+
+				match service {
+					Some(service) => *service,
+					// Services that are not part of the project exist only at runtime
+					None => return Resolution::Dynamic,
+				}
+			}
+		};
+	}
+
+	Resolution::Found(current)
+}
+
+/// Find every elementary cycle, each returned once as a list of edges
+/// starting at its lowest node, the last edge being the one that closes it
+fn find_cycles(graph: &RequireGraph) -> Vec<Vec<Edge>> {
+	let mut adjacency: Vec<Vec<Edge>> = vec![Vec::new(); graph.nodes.len()];
+
+	for edge in &graph.edges {
+		adjacency[edge.from].push(*edge);
+	}
+
+	let components = strongly_connected(&adjacency);
+
+	let mut cycles = Vec::new();
+	let mut reported = HashSet::new();
+
+	// Cycles through `start` that only visit higher nodes of its
+	// component, so every cycle is found from exactly one node
+	fn search(
+		start: usize,
+		node: usize,
+		adjacency: &[Vec<Edge>],
+		components: &[usize],
+		path: &mut Vec<Edge>,
+		reported: &mut HashSet<Vec<usize>>,
+		cycles: &mut Vec<Vec<Edge>>,
+	) {
+		for edge in &adjacency[node] {
+			if edge.to < start || components[edge.to] != components[start] {
+				continue;
+			}
+
+			if edge.to == start {
+				let mut cycle = path.clone();
+				cycle.push(*edge);
+
+				// The same module can be required more than once
+				if reported.insert(cycle.iter().map(|edge| edge.from).collect()) {
+					cycles.push(cycle);
+				}
+			} else if !path.iter().any(|step| step.from == edge.to) {
+				path.push(*edge);
+				search(start, edge.to, adjacency, components, path, reported, cycles);
+				path.pop();
+			}
+		}
+	}
+
+	for start in 0..graph.nodes.len() {
+		search(
+			start,
+			start,
+			&adjacency,
+			&components,
+			&mut Vec::new(),
+			&mut reported,
+			&mut cycles,
+		);
+	}
+
+	cycles
+}
+
+/// Returns the strongly connected component of every node, using Tarjan's algorithm
+fn strongly_connected(adjacency: &[Vec<Edge>]) -> Vec<usize> {
+	struct State {
+		index: Vec<Option<usize>>,
+		low: Vec<usize>,
+		stack: Vec<usize>,
+		on_stack: Vec<bool>,
+		components: Vec<usize>,
+		next_index: usize,
+		next_component: usize,
+	}
+
+	fn connect(node: usize, adjacency: &[Vec<Edge>], state: &mut State) {
+		state.index[node] = Some(state.next_index);
+		state.low[node] = state.next_index;
+		state.next_index += 1;
+
+		state.stack.push(node);
+		state.on_stack[node] = true;
+
+		for edge in &adjacency[node] {
+			match state.index[edge.to] {
+				None => {
+					connect(edge.to, adjacency, state);
+					state.low[node] = state.low[node].min(state.low[edge.to]);
+				}
+				Some(index) if state.on_stack[edge.to] => state.low[node] = state.low[node].min(index),
+				Some(_) => {}
+			}
+		}
+
+		if Some(state.low[node]) == state.index[node] {
+			while let Some(member) = state.stack.pop() {
+				state.on_stack[member] = false;
+				state.components[member] = state.next_component;
+
+				if member == node {
+					break;
+				}
+			}
+
+			state.next_component += 1;
+		}
+	}
+
+	let count = adjacency.len();
+
+	let mut state = State {
+		index: vec![None; count],
+		low: vec![0; count],
+		stack: Vec::new(),
+		on_stack: vec![false; count],
+		components: vec![0; count],
+		next_index: 0,
+		next_component: 0,
+	};
+
+	for node in 0..count {
+		if state.index[node].is_none() {
+			connect(node, adjacency, &mut state);
+		}
+	}
+
+	state.components
+}
@@ -0,0 +1,191 @@
+use anyhow::{bail, Result};
+use colored::Colorize;
+use regex::Regex;
+use std::{collections::HashSet, path::Path};
+
+use super::{
+	banned_apis,
+	lexer::{self, Token},
+	line_snippet,
+	suppression::Suppressions,
+	Diagnostic, Severity,
+};
+use crate::{
+	ext::ResultExt,
+	glob::Glob,
+	plugin::config::{CustomRule, ValidationConfig},
+};
+
+#[derive(Debug)]
+enum Matcher {
+	Regex(Regex),
+	/// Sequence of tokens, `None` is a wildcard (`$_`) matching any single token
+	Tokens(Vec<Option<Token>>),
+}
+
+#[derive(Debug)]
+struct Rule {
+	id: String,
+	message: String,
+	severity: Severity,
+	matcher: Matcher,
+	include: Vec<Glob>,
+	exclude: Vec<Glob>,
+}
+
+/// Set of source rules compiled from the `validation` section of `fluxo.config.json`
+#[derive(Debug)]
+pub struct RuleSet {
+	banned_apis: Vec<String>,
+	rules: Vec<Rule>,
+	include: Vec<Glob>,
+	exclude: Vec<Glob>,
+}
+
+impl RuleSet {
+	pub fn new(config: &ValidationConfig) -> Result<Self> {
+		let mut ids = HashSet::from([banned_apis::RULE.to_owned()]);
+		let mut rules = Vec::new();
+
+		for rule in &config.custom_rules {
+			if rule.id.trim().is_empty() {
+				bail!("Custom rule id cannot be empty");
+			}
+
+			if !ids.insert(rule.id.clone()) {
+				bail!("Custom rule id {} is used more than once", rule.id.bold());
+			}
+
+			rules.push(Rule::new(rule)?);
+		}
+
+		Ok(Self {
+			banned_apis: config.banned_apis.clone(),
+			rules,
+			include: config.include.clone(),
+			exclude: config.exclude.clone(),
+		})
+	}
+
+	/// Whether the file at `path` (relative to the workspace) should be checked at all
+	pub fn is_included(&self, path: &Path) -> bool {
+		is_included(path, &self.include, &self.exclude)
+	}
+
+	/// Run every rule against the given `source`, `path`
+	/// should be relative to the workspace directory
+	pub fn check(&self, path: &Path, source: &str) -> Vec<Diagnostic> {
+		if !self.is_included(path) {
+			return Vec::new();
+		}
+
+		let tokens = lexer::tokenize(source);
+		let suppressions = Suppressions::parse(&tokens);
+
+		let mut diagnostics = banned_apis::check(path, source, &self.banned_apis, Severity::Warning);
+
+		let code: Vec<Token> = tokens.into_iter().filter(|token| !token.is_comment()).collect();
+
+		for rule in &self.rules {
+			if is_included(path, &rule.include, &rule.exclude) {
+				diagnostics.extend(rule.check(path, source, &code));
+			}
+		}
+
+		diagnostics.retain(|diagnostic| !suppressions.is_suppressed(&diagnostic.rule, diagnostic.line));
+		diagnostics
+	}
+}
+
+impl Rule {
+	fn new(rule: &CustomRule) -> Result<Self> {
+		let matcher = match (&rule.regex, &rule.pattern) {
+			(Some(regex), None) => {
+				Matcher::Regex(Regex::new(regex).with_desc(|| format!("Invalid regex in rule {}", rule.id.bold()))?)
+			}
+			(None, Some(pattern)) => {
+				let tokens = lexer::tokenize_code(pattern);
+				let mut matcher = Vec::new();
+				let mut index = 0;
+
+				if tokens.is_empty() {
+					bail!("Token pattern of rule {} is empty", rule.id.bold());
+				}
+
+				while index < tokens.len() {
+					if tokens[index].is_symbol("$") && tokens.get(index + 1).is_some_and(|t| t.is_name("_")) {
+						matcher.push(None);
+						index += 2;
+					} else {
+						matcher.push(Some(tokens[index].clone()));
+						index += 1;
+					}
+				}
+
+				Matcher::Tokens(matcher)
+			}
+			_ => bail!(
+				"Custom rule {} must have either {} or {} set",
+				rule.id.bold(),
+				"regex".bold(),
+				"pattern".bold()
+			),
+		};
+
+		Ok(Self {
+			id: rule.id.clone(),
+			message: rule.message.clone(),
+			severity: rule.severity,
+			matcher,
+			include: rule.include.clone(),
+			exclude: rule.exclude.clone(),
+		})
+	}
+
+	fn check(&self, path: &Path, source: &str, code: &[Token]) -> Vec<Diagnostic> {
+		let locations: Vec<(usize, usize)> = match &self.matcher {
+			Matcher::Regex(regex) => regex
+				.find_iter(source)
+				.map(|found| {
+					let before = &source[..found.start()];
+					let line = before.matches('\n').count() + 1;
+					let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+
+					(line, column)
+				})
+				.collect(),
+			Matcher::Tokens(pattern) => (0..code.len())
+				.filter(|&index| {
+					pattern.iter().enumerate().all(|(offset, expected)| {
+						let Some(token) = code.get(index + offset) else {
+							return false;
+						};
+
+						expected
+							.as_ref()
+							.is_none_or(|expected| token.kind == expected.kind && token.value == expected.value)
+					})
+				})
+				.map(|index| (code[index].line, code[index].column))
+				.collect(),
+		};
+
+		locations
+			.into_iter()
+			.map(|(line, column)| {
+				Diagnostic::new(&self.id, self.severity, self.message.clone())
+					.with_path(path)
+					.with_location(line, column)
+					.with_snippet(line_snippet(source, line))
+			})
+			.collect()
+	}
+}
+
+fn is_included(path: &Path, include: &[Glob], exclude: &[Glob]) -> bool {
+	if !include.is_empty() && !include.iter().any(|glob| glob.matches_path(path)) {
+		return false;
+	}
+
+	!exclude.iter().any(|glob| glob.matches_path_with_dir(path))
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use super::lexer::Token;
+
+const IGNORE: &str = "fluxo-ignore";
+const IGNORE_FILE: &str = "fluxo-ignore-file";
+
+/// Rules suppressed with inline comments:
+///
+/// - `-- fluxo-ignore: rule-id, other-rule` - trailing comment
+///   suppresses its own line, standalone one the line below it
+/// - `-- fluxo-ignore-file: rule-id` - suppresses the whole file
+///
+/// Omitting the rule list suppresses every rule
+#[derive(Debug, Default)]
+pub struct Suppressions {
+	file: Option<Suppressed>,
+	lines: HashMap<usize, Suppressed>,
+}
+
+/// Rules suppressed by all directives for a file or line
+#[derive(Debug)]
+enum Suppressed {
+	All,
+	Rules(Vec<String>),
+}
+
+impl Default for Suppressed {
+	fn default() -> Self {
+		Self::Rules(Vec::new())
+	}
+}
+
+impl Suppressed {
+	/// A directive without rules wins over the ones listing them
+	fn add(&mut self, rules: Vec<String>) {
+		match self {
+			Self::All => {}
+			_ if rules.is_empty() => *self = Self::All,
+			Self::Rules(existing) => existing.extend(rules),
+		}
+	}
+
+	fn matches(&self, rule: &str) -> bool {
+		match self {
+			Self::All => true,
+			Self::Rules(rules) => rules.iter().any(|r| r == rule),
+		}
+	}
+}
+
+impl Suppressions {
+	pub fn parse(tokens: &[Token]) -> Self {
+		let mut suppressions = Self::default();
+		let mut last_code_line = 0;
+
+		for token in tokens {
+			if !token.is_comment() {
+				last_code_line = token.line + token.value.matches('\n').count();
+				continue;
+			}
+
+			let comment = token.value.trim();
+
+			let (is_file, rules) = if let Some(rules) = comment.strip_prefix(IGNORE_FILE) {
+				(true, rules)
+			} else if let Some(rules) = comment.strip_prefix(IGNORE) {
+				(false, rules)
+			} else {
+				continue;
+			};
+
+			let rules = match rules.trim_start().strip_prefix(':') {
+				Some(rules) => rules,
+				None if rules.trim().is_empty() => "",
+				// Something like `fluxo-ignored`, not a directive
+				None => continue,
+			};
+
+			let rules: Vec<String> = rules
+				.split(',')
+				.map(|rule| rule.trim().to_owned())
+				.filter(|rule| !rule.is_empty())
+				.collect();
+
+			if is_file {
+				suppressions.file.get_or_insert_with(Default::default).add(rules);
+				continue;
+			}
+
+			let line = if last_code_line == token.line {
+				token.line
+			} else {
+				token.line + token.value.matches('\n').count() + 1
+			};
+
+			suppressions.lines.entry(line).or_default().add(rules);
+		}
+
+		suppressions
+	}
+
+	pub fn is_suppressed(&self, rule: &str, line: Option<usize>) -> bool {
+		let matches = |suppressed: &Suppressed| suppressed.matches(rule);
+
+		if self.file.as_ref().is_some_and(matches) {
+			return true;
+		}
+
+		line.and_then(|line| self.lines.get(&line)).is_some_and(matches)
+	}
+}
@@ -0,0 +1,101 @@
+use log::trace;
+use std::path::{Path, PathBuf};
+
+use super::{
+	checks::{self, Check, Context},
+	report::Report,
+	Diagnostic, Severity,
+};
+use crate::plugin::{config::PluginConfig, CONFIG_FILE};
+
+/// Runs a set of checks against the plugin workspace. This is the only
+/// validation entry point, used by the CLI, HTTP server and Studio plugin
+pub struct Validator {
+	checks: Vec<Box<dyn Check>>,
+	cache: bool,
+	changed: Option<Vec<PathBuf>>,
+}
+
+impl Validator {
+	/// Create a validator with all default checks
+	pub fn new() -> Self {
+		Self {
+			checks: checks::defaults(),
+			cache: true,
+			changed: None,
+		}
+	}
+
+	/// Create a validator without any checks
+	pub fn empty() -> Self {
+		Self {
+			checks: Vec::new(),
+			cache: true,
+			changed: None,
+		}
+	}
+
+	pub fn with_check(mut self, check: impl Check + 'static) -> Self {
+		self.checks.push(Box::new(check));
+		self
+	}
+
+	/// Remove the check with the given name, if present
+	pub fn without(mut self, name: &str) -> Self {
+		self.checks.retain(|check| check.name() != name);
+		self
+	}
+
+	/// Check every file again instead of reusing cached results
+	pub fn without_cache(mut self) -> Self {
+		self.cache = false;
+		self
+	}
+
+	/// Check only files in `changed` again and take results of
+	/// the other files from the cache, paths are relative to the workspace
+	pub fn only_changed(mut self, changed: Vec<PathBuf>) -> Self {
+		self.changed = Some(changed);
+		self
+	}
+
+	pub fn checks(&self) -> impl Iterator<Item = &'static str> + '_ {
+		self.checks.iter().map(|check| check.name())
+	}
+
+	pub fn run(&self, workspace_dir: &Path) -> Report {
+		let mut diagnostics = Vec::new();
+
+		let config = PluginConfig::load(workspace_dir).unwrap_or_else(|err| {
+			diagnostics
+				.push(Diagnostic::new("config", Severity::Error, err.to_string()).with_path(Path::new(CONFIG_FILE)));
+
+			PluginConfig::default()
+		});
+
+		let context = Context::new(workspace_dir, config)
+			.with_cache(self.cache)
+			.with_changed(self.changed.clone());
+
+		for check in &self.checks {
+			trace!("Running {} check", check.name());
+
+			match check.run(&context) {
+				Ok(found) => diagnostics.extend(found),
+				Err(err) => diagnostics.push(Diagnostic::new(
+					check.name(),
+					Severity::Error,
+					format!("Failed to run {} check: {}", check.name(), err),
+				)),
+			}
+		}
+
+		Report::new(diagnostics)
+	}
+}
+
+impl Default for Validator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -46,6 +46,9 @@ impl VfsBackend for StdBackend {
 			paths.push(entry?.path());
 		}
 
+		// Order of entries depends on the file system
+		paths.sort();
+
 		Ok(paths)
 	}
 
@@ -4,29 +4,267 @@
 
 local Sync = {}
 
+-- Services
+local CollectionService = game:GetService("CollectionService")
+
+local NULL_REF = "00000000000000000000000000000000"
+
+-- Instances created from the last snapshot, keyed by the id the CLI gave them.
+-- Every snapshot comes with new ids, so the map is rebuilt from scratch each time
+Sync.instances = {}
+Sync.root = nil
+
+-- Converts a property value serialized by the CLI, `{ [type] = value }`, into a Roblox value.
+-- Refs are returned as ids and resolved once all instances exist
+local function decodeValue(variant)
+    local kind, value = next(variant)
+    
+    if kind == "String" or kind == "Bool" or kind == "Int32" or kind == "Int64"
+        or kind == "Float32" or kind == "Float64" or kind == "Enum" or kind == "Content" then
+        return value
+    elseif kind == "Vector2" then
+        return Vector2.new(value[1], value[2])
+    elseif kind == "Vector3" then
+        return Vector3.new(value[1], value[2], value[3])
+    elseif kind == "Color3" then
+        return Color3.new(value[1], value[2], value[3])
+    elseif kind == "Color3uint8" then
+        return Color3.fromRGB(value[1], value[2], value[3])
+    elseif kind == "BrickColor" then
+        return BrickColor.new(value)
+    elseif kind == "UDim" then
+        return UDim.new(value[1], value[2])
+    elseif kind == "UDim2" then
+        return UDim2.new(value[1][1], value[1][2], value[2][1], value[2][2])
+    elseif kind == "NumberRange" then
+        return NumberRange.new(value[1], value[2])
+    elseif kind == "Rect" then
+        return Rect.new(value[1][1], value[1][2], value[2][1], value[2][2])
+    elseif kind == "CFrame" then
+        local position = value.position
+        local orientation = value.orientation
+        
+        return CFrame.new(
+            position[1], position[2], position[3],
+            orientation[1][1], orientation[1][2], orientation[1][3],
+            orientation[2][1], orientation[2][2], orientation[2][3],
+            orientation[3][1], orientation[3][2], orientation[3][3]
+        )
+    end
+    
+    return nil, kind
+end
+
+local function applyProperties(instance, properties, refs, dashboard)
+    for name, variant in pairs(properties or {}) do
+        local kind, value = next(variant)
+        
+        if kind == "Ref" then
+            table.insert(refs, {instance = instance, name = name, id = value})
+        elseif kind == "Tags" then
+            for _, tag in ipairs(value) do
+                CollectionService:AddTag(instance, tag)
+            end
+        elseif kind == "Attributes" then
+            for attribute, attributeVariant in pairs(value) do
+                local decoded = decodeValue(attributeVariant)
+                
+                if decoded ~= nil then
+                    instance:SetAttribute(attribute, decoded)
+                end
+            end
+        else
+            local decoded, unsupported = decodeValue(variant)
+            
+            local ok = decoded ~= nil and pcall(function()
+                instance[name] = decoded
+            end)
+            
+            if not ok then
+                dashboard:addLog("⚠️ Skipped " .. instance:GetFullName() .. "." .. name .. " (" .. tostring(unsupported or kind) .. ")")
+            end
+        end
+    end
+end
+
+-- Refs can point anywhere in the tree, so they are set after all instances are created
+local function resolveRefs(refs)
+    for _, ref in ipairs(refs) do
+        local target = if ref.id == NULL_REF then nil else Sync.instances[ref.id]
+        
+        pcall(function()
+            ref.instance[ref.name] = target
+        end)
+    end
+end
+
+-- Creates the instance with all its descendants, parented last so Studio sees a complete tree
+local function createInstance(snapshot, parent, refs, dashboard)
+    local ok, instance = pcall(Instance.new, snapshot.class)
+    
+    if not ok then
+        dashboard:addLog("❌ Cannot create " .. snapshot.name .. ": unknown class " .. snapshot.class)
+        return nil
+    end
+    
+    instance.Name = snapshot.name
+    applyProperties(instance, snapshot.properties, refs, dashboard)
+    Sync.instances[snapshot.id] = instance
+    
+    for _, child in ipairs(snapshot.children or {}) do
+        createInstance(child, instance, refs, dashboard)
+    end
+    
+    instance.Parent = parent
+    
+    return instance
+end
+
+-- Drops ids of instances that were destroyed together with their ancestors
+local function forgetDestroyed()
+    for id, instance in pairs(Sync.instances) do
+        if instance ~= game and not instance:IsDescendantOf(game) then
+            Sync.instances[id] = nil
+        end
+    end
+end
+
+-- Replaces everything synced before with the snapshot, `DataModel` roots are merged into the services
+function Sync.applySnapshot(snapshot, dashboard)
+    if Sync.root then
+        Sync.root:Destroy()
+        Sync.root = nil
+    end
+    
+    Sync.instances = {}
+    
+    local refs = {}
+    
+    if snapshot.class == "DataModel" then
+        Sync.instances[snapshot.id] = game
+        
+        for _, child in ipairs(snapshot.children or {}) do
+            local ok, service = pcall(game.GetService, game, child.class)
+            
+            if ok and service then
+                Sync.instances[child.id] = service
+                applyProperties(service, child.properties, refs, dashboard)
+                
+                for _, descendant in ipairs(child.children or {}) do
+                    local existing = service:FindFirstChild(descendant.name)
+                    
+                    if existing then
+                        existing:Destroy()
+                    end
+                    
+                    createInstance(descendant, service, refs, dashboard)
+                end
+            else
+                createInstance(child, game:GetService("ServerStorage"), refs, dashboard)
+            end
+        end
+    else
+        Sync.root = createInstance(snapshot, game:GetService("ServerStorage"), refs, dashboard)
+    end
+    
+    resolveRefs(refs)
+end
+
+-- Applies additions, updates and removals sent by `fluxo sync --watch`
+function Sync.applyChanges(changes, dashboard)
+    local refs = {}
+    
+    for _, id in ipairs(changes.removals or {}) do
+        local instance = Sync.instances[id]
+        
+        if instance then
+            if instance == Sync.root then
+                Sync.root = nil
+            end
+            
+            instance:Destroy()
+            Sync.instances[id] = nil
+        end
+    end
+    
+    forgetDestroyed()
+    
+    for _, snapshot in ipairs(changes.additions or {}) do
+        local parent = Sync.instances[snapshot.parent]
+        
+        if parent then
+            createInstance(snapshot, parent, refs, dashboard)
+        else
+            dashboard:addLog("⚠️ Skipped " .. snapshot.name .. ": parent is not synced")
+        end
+    end
+    
+    for _, update in ipairs(changes.updates or {}) do
+        local instance = Sync.instances[update.id]
+        
+        if not instance then
+            dashboard:addLog("⚠️ Skipped update of an instance that is not synced")
+            continue
+        end
+        
+        -- Class cannot be changed in place, so the instance is replaced and keeps its children
+        if update.class and update.class ~= instance.ClassName then
+            local ok, replacement = pcall(Instance.new, update.class)
+            
+            if ok then
+                replacement.Name = instance.Name
+                
+                for _, child in ipairs(instance:GetChildren()) do
+                    child.Parent = replacement
+                end
+                
+                replacement.Parent = instance.Parent
+                
+                if instance == Sync.root then
+                    Sync.root = replacement
+                end
+                
+                instance:Destroy()
+                instance = replacement
+                Sync.instances[update.id] = replacement
+            end
+        end
+        
+        if update.name then
+            instance.Name = update.name
+        end
+        
+        applyProperties(instance, update.properties, refs, dashboard)
+    end
+    
+    resolveRefs(refs)
+end
+
 function Sync.handleSyncRequest(data, dashboard)
     dashboard:addLog("🔄 Received sync request from CLI")
     
-    if not data or not data.files then
-        dashboard:addLog("❌ Invalid sync request: missing files")
-        return {success = false, error = "Missing files"}
+    if not data or not data.snapshot then
+        dashboard:addLog("❌ Invalid sync request: missing snapshot")
+        return {success = false, error = "Missing snapshot"}
     end
     
-    local files = data.files
+    local snapshot = data.snapshot
     local metadata = data.metadata or {}
     
-    dashboard:addLog("📁 Syncing " .. #files .. " files...")
+    dashboard:addLog("📁 Syncing " .. (data.instances or 0) .. " instances...")
     
     -- Update project information in dashboard
     dashboard:updateProject({
-        name = metadata.name or "Unknown Project",
+        name = metadata.name or snapshot.name or "Unknown Project",
         path = data.projectPath or "",
         lastSync = os.date("%H:%M:%S")
     })
     
-    -- Process each file
-    for filename, content in pairs(files) do
-        dashboard:addLog("📄 Synced: " .. filename)
+    local ok, error = pcall(Sync.applySnapshot, snapshot, dashboard)
+    
+    if not ok then
+        dashboard:addLog("❌ Sync failed: " .. tostring(error))
+        return {success = false, error = tostring(error)}
     end
     
     dashboard:addLog("✅ Sync completed successfully")
@@ -35,6 +273,19 @@ function Sync.handleSyncRequest(data, dashboard)
     return {success = true, message = "Files synced successfully"}
 end
 
+function Sync.handleSyncChanges(data, dashboard)
+    local ok, error = pcall(Sync.applyChanges, data.changes or data, dashboard)
+    
+    if not ok then
+        dashboard:addLog("❌ Failed to apply changes: " .. tostring(error))
+        return {success = false, error = tostring(error)}
+    end
+    
+    dashboard:addLog("✅ Applied changes")
+    
+    return {success = true}
+end
+
 function Sync.showDiffViewer(oldContent, newContent, filename, dashboard)
     -- Create diff viewer modal
     local screenGui = Instance.new("ScreenGui")
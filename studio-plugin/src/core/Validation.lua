@@ -7,45 +7,26 @@ local Validation = {}
 function Validation.validateProject(projectPath, dashboard)
     dashboard:addLog("🔍 Starting project validation...")
     
-    local issues = {}
-    local warnings = {}
-    local suggestions = {}
-    
-    -- Basic structure validation
-    if not Validation.checkProjectStructure(projectPath) then
-        table.insert(issues, "Invalid project structure")
+    if not dashboard.httpClient then
+        dashboard:addLog("❌ Validation requires a connection to the Fluxo CLI")
+        return nil
     end
     
-    -- Configuration validation
-    local configIssues = Validation.validateConfiguration(projectPath)
-    for _, issue in ipairs(configIssues) do
-        table.insert(issues, issue)
-    end
+    -- Validation itself runs in the CLI so Studio shows exactly the same results as the terminal
+    local result = dashboard.httpClient.post("/validate", {}, nil, function(error)
+        dashboard:addLog("❌ " .. error)
+    end)
     
-    -- File validation
-    local fileIssues, fileWarnings = Validation.validateFiles(projectPath)
-    for _, issue in ipairs(fileIssues) do
-        table.insert(issues, issue)
-    end
-    for _, warning in ipairs(fileWarnings) do
-        table.insert(warnings, warning)
+    if not result then
+        return nil
     end
     
-    -- Best practices check
-    local bestPractices = Validation.checkBestPractices(projectPath)
-    for _, suggestion in ipairs(bestPractices) do
-        table.insert(suggestions, suggestion)
+    if not result.diagnostics then
+        dashboard:addLog("❌ Validation failed: " .. tostring(result.error))
+        return nil
     end
     
-    -- Generate report
-    local report = {
-        timestamp = os.date("%Y-%m-%d %H:%M:%S"),
-        projectPath = projectPath,
-        issues = issues,
-        warnings = warnings,
-        suggestions = suggestions,
-        isValid = #issues == 0
-    }
+    local report = Validation.fromDiagnostics(result, projectPath)
     
     -- Display results
     Validation.showValidationReport(report, dashboard)
@@ -53,54 +34,46 @@ function Validation.validateProject(projectPath, dashboard)
     return report
 end
 
-function Validation.checkProjectStructure(projectPath)
-    -- Basic project structure checks
-    -- This would typically check for required files like:
-    -- - project.json or default.project.json
-    -- - src folder
-    -- - fluxo.config.json
-    return true -- Placeholder
-end
-
-function Validation.validateConfiguration(projectPath)
-    local issues = {}
-    
-    -- Check fluxo.config.json
-    -- This would validate:
-    -- - Required fields
-    -- - Valid values
-    -- - Compatibility settings
-    
-    return issues
-end
-
-function Validation.validateFiles(projectPath)
+-- Converts the CLI validation report into issues, warnings and suggestions
+function Validation.fromDiagnostics(result, projectPath)
     local issues = {}
     local warnings = {}
-    
-    -- File-specific validations:
-    -- - Lua syntax checking
-    -- - Asset validation
-    -- - Naming conventions
-    -- - File size limits
-    
-    return issues, warnings
-end
-
-function Validation.checkBestPractices(projectPath)
     local suggestions = {}
     
-    -- Best practice suggestions:
-    -- - Code organization
-    -- - Performance optimizations
-    -- - Security considerations
-    -- - Documentation completeness
+    local groups = {
+        error = issues,
+        warning = warnings,
+        info = suggestions,
+    }
     
-    table.insert(suggestions, "Consider adding README.md documentation")
-    table.insert(suggestions, "Add version control ignore files")
-    table.insert(suggestions, "Use consistent naming conventions")
+    for _, diagnostic in ipairs(result.diagnostics) do
+        local text = diagnostic.message .. " [" .. diagnostic.rule .. "]"
+        
+        if diagnostic.path then
+            local location = diagnostic.path
+            
+            if diagnostic.line then
+                location = location .. ":" .. diagnostic.line
+            end
+            
+            if diagnostic.column then
+                location = location .. ":" .. diagnostic.column
+            end
+            
+            text = location .. ": " .. text
+        end
+        
+        table.insert(groups[diagnostic.severity] or issues, text)
+    end
     
-    return suggestions
+    return {
+        timestamp = result.timestamp,
+        projectPath = projectPath,
+        issues = issues,
+        warnings = warnings,
+        suggestions = suggestions,
+        isValid = result.success
+    }
 end
 
 function Validation.showValidationReport(report, dashboard)
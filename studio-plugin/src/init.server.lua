@@ -42,6 +42,11 @@ dashboard:setMetadataEditor(MetadataEditor)
 dashboard:setHttpClient(HttpClient)
 dashboard:setLogger(Logger)
 
+-- Apply the project tree sent by `fluxo sync`
+server.onSyncRequest.Event:Connect(function(data)
+    Sync.handleSyncRequest(data, dashboard)
+end)
+
 -- Button click handler
 mainButton.Click:Connect(function()
     dashboard:toggle()
@@ -0,0 +1,163 @@
+use fluxo::{
+	core::{canonical::canonicalize, Core},
+	project::Project,
+	sync::SyncRequest,
+};
+use rbx_dom_weak::{types::Variant, ustr, InstanceBuilder, WeakDom};
+use std::{fs, path::PathBuf};
+
+mod common;
+
+use common::workspace;
+
+fn serialize(dom: &WeakDom) -> Vec<u8> {
+	let (dom, roots) = canonicalize(dom, dom.root().children());
+
+	let mut buffer = Vec::new();
+	rbx_binary::to_writer(&mut buffer, &dom, &roots).unwrap();
+
+	buffer
+}
+
+/// Model with a `Target` folder and an `ObjectValue` pointing to it
+fn model(reversed: bool) -> WeakDom {
+	let mut dom = WeakDom::new(InstanceBuilder::new("DataModel"));
+	let root = dom.insert(dom.root_ref(), InstanceBuilder::new("Folder").with_name("Plugin"));
+
+	let mut names = vec!["Alpha", "Beta", "Gamma"];
+
+	if reversed {
+		names.reverse();
+	}
+
+	for name in names {
+		dom.insert(root, InstanceBuilder::new("ModuleScript").with_name(name));
+	}
+
+	let target = dom.insert(root, InstanceBuilder::new("Folder").with_name("Target"));
+
+	dom.insert(
+		root,
+		InstanceBuilder::new("ObjectValue")
+			.with_name("Link")
+			.with_property("Value", Variant::Ref(target)),
+	);
+
+	dom
+}
+
+#[test]
+fn canonical_dom() {
+	let dom = model(false);
+
+	assert_eq!(serialize(&dom), serialize(&model(true)));
+
+	let (canonical, roots) = canonicalize(&dom, dom.root().children());
+	let root = canonical.get_by_ref(roots[0]).unwrap();
+
+	let names: Vec<_> = root
+		.children()
+		.iter()
+		.map(|id| canonical.get_by_ref(*id).unwrap().name.as_str())
+		.collect();
+
+	assert_eq!(names, vec!["Alpha", "Beta", "Gamma", "Link", "Target"]);
+
+	// References point to copied instances and referents do not change between builds
+	let link = canonical.get_by_ref(root.children()[3]).unwrap();
+	let target = root.children()[4];
+
+	assert_eq!(link.properties.get(&ustr("Value")), Some(&Variant::Ref(target)));
+	assert_eq!(canonicalize(&dom, dom.root().children()).1, roots);
+}
+
+#[test]
+fn reproducible() {
+	let mut files = vec![
+		("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+		("src/init.luau", "return 0"),
+		("src/A/init.meta.json", r#"{"attributes": {"b": 1, "a": 2}}"#),
+		("src/A/One.luau", "return 1"),
+		("src/A/Two.luau", "return 2"),
+		("src/B/Three.luau", "return 3"),
+	];
+
+	let first = workspace("first", &files);
+
+	files.reverse();
+	let second = workspace("second", &files);
+
+	let build = |dir: &PathBuf| {
+		let project = Project::load(&dir.join("default.project.json")).unwrap();
+		Core::new(project, false)
+			.unwrap()
+			.build(&dir.join("Test.rbxm"), false)
+			.unwrap()
+	};
+
+	let hash = build(&first);
+
+	assert_eq!(hash, build(&first));
+	assert_eq!(hash, build(&second));
+	assert_eq!(
+		fs::read(first.join("Test.rbxm")).unwrap(),
+		fs::read(second.join("Test.rbxm")).unwrap()
+	);
+
+	fs::remove_dir_all(first).unwrap();
+	fs::remove_dir_all(second).unwrap();
+}
+
+#[test]
+fn sync_request() {
+	let dir = workspace(
+		"sync",
+		&[
+			("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+			("plugin.meta.json", r#"{"name": "Test"}"#),
+			("src/init.luau", "return 0"),
+			("src/Config/init.meta.json", r#"{"className": "Configuration"}"#),
+			("src/Config/Value.luau", "return 1"),
+		],
+	);
+
+	// Binary models could not be synced before, as files were read as text
+	fs::write(dir.join("src/Model.rbxm"), {
+		let dom = model(false);
+		let mut buffer = Vec::new();
+		rbx_binary::to_writer(&mut buffer, &dom, dom.root().children()).unwrap();
+		buffer
+	})
+	.unwrap();
+
+	let core = Core::new(Project::load(&dir.join("default.project.json")).unwrap(), false).unwrap();
+	let request = SyncRequest::new(&core).unwrap();
+
+	assert_eq!(request.hash, core.build(&dir.join("Test.rbxm"), false).unwrap());
+	assert_eq!(request.metadata["name"], "Test");
+
+	let built = rbx_binary::from_reader(fs::read(dir.join("Test.rbxm")).unwrap().as_slice()).unwrap();
+	assert_eq!(request.instances, built.descendants().count() - 1);
+
+	let model = request
+		.snapshot
+		.children
+		.iter()
+		.find(|child| child.name == "Model")
+		.unwrap();
+	assert_eq!(model.children.len(), 5);
+
+	let config = request
+		.snapshot
+		.children
+		.iter()
+		.find(|child| child.name == "Config")
+		.unwrap();
+	assert_eq!(config.class, "Configuration");
+	assert_eq!(
+		config.children[0].properties.get(&ustr("Source")),
+		Some(&Variant::String("return 1".into()))
+	);
+
+	fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,24 @@
+use std::{env, fs, path::PathBuf};
+
+/// Create a fresh directory named after the test binary and `name`
+/// in the system temp directory, with `files` written to it
+pub fn workspace(name: &str, files: &[(&str, &str)]) -> PathBuf {
+	let dir = env::temp_dir().join(format!(
+		"fluxo-{}-{}-{}",
+		env!("CARGO_CRATE_NAME"),
+		name,
+		std::process::id()
+	));
+
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(&dir).unwrap();
+
+	for (path, contents) in files {
+		let path = dir.join(path);
+
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(path, contents).unwrap();
+	}
+
+	dir
+}
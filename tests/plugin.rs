@@ -0,0 +1,356 @@
+mod common;
+
+mod version {
+	use fluxo::plugin::version::Bump;
+	use semver::Version;
+
+	fn bump(version: &str, bump: Bump) -> String {
+		bump.apply(&Version::parse(version).unwrap()).unwrap().to_string()
+	}
+
+	#[test]
+	fn stable() {
+		assert_eq!(bump("1.2.3", Bump::Major), "2.0.0");
+		assert_eq!(bump("1.2.3", Bump::Minor), "1.3.0");
+		assert_eq!(bump("1.2.3", Bump::Patch), "1.2.4");
+		assert_eq!(bump("1.2.3+build.5", Bump::Patch), "1.2.4");
+	}
+
+	#[test]
+	fn prerelease() {
+		assert_eq!(bump("1.2.3", Bump::Prerelease), "1.2.4-beta.1");
+		assert_eq!(bump("1.2.4-beta.1", Bump::Prerelease), "1.2.4-beta.2");
+		assert_eq!(bump("1.2.4-rc", Bump::Prerelease), "1.2.4-rc.1");
+		assert_eq!(bump("1.2.4-alpha.9", Bump::Prerelease), "1.2.4-alpha.10");
+	}
+
+	#[test]
+	fn release_prerelease() {
+		assert_eq!(bump("1.2.4-beta.2", Bump::Patch), "1.2.4");
+		assert_eq!(bump("1.3.0-beta.2", Bump::Minor), "1.3.0");
+		assert_eq!(bump("1.3.1-beta.2", Bump::Minor), "1.4.0");
+		assert_eq!(bump("2.0.0-beta.2", Bump::Major), "2.0.0");
+		assert_eq!(bump("2.1.0-beta.2", Bump::Major), "3.0.0");
+	}
+}
+
+mod changelog {
+	use chrono::NaiveDate;
+	use fluxo::plugin::changelog::{excerpt, release};
+
+	fn date() -> NaiveDate {
+		NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()
+	}
+
+	#[test]
+	fn template() {
+		let changelog = "# Plugin Changelog\n\n## Unreleased Changes\n\n-\n";
+
+		assert_eq!(
+			release(changelog, "1.0.0", date(), Some("Initial release")).unwrap(),
+			"# Plugin Changelog\n\n## Unreleased Changes\n\n## [1.0.0] - 2025-06-01\n\n- Initial release\n"
+		);
+	}
+
+	#[test]
+	fn unreleased_entries() {
+		let changelog =
+			"# Changelog\n\n## [Unreleased]\n\n- Added X\n- Fixed Y\n\n## [1.0.0] - 2025-01-01\n\n- Initial release\n";
+
+		assert_eq!(
+			release(changelog, "1.1.0", date(), Some("Faster sync\n- Smaller icon")).unwrap(),
+			"# Changelog\n\n## [Unreleased]\n\n## [1.1.0] - 2025-06-01\n\n- Added X\n- Fixed Y\n- Faster sync\n- Smaller icon\n\n## [1.0.0] - 2025-01-01\n\n- Initial release\n"
+		);
+	}
+
+	#[test]
+	fn grouped_notes() {
+		let changelog = "# Changelog\n\n## [Unreleased]\n\n- Added X\n";
+
+		assert_eq!(
+			release(changelog, "1.1.0", date(), Some("### Features\n\n- Icons\n\n### Bug Fixes\n\n- Crash")).unwrap(),
+			"# Changelog\n\n## [Unreleased]\n\n## [1.1.0] - 2025-06-01\n\n- Added X\n\n### Features\n\n- Icons\n\n### Bug Fixes\n\n- Crash\n"
+		);
+	}
+
+	#[test]
+	fn no_unreleased_section() {
+		let changelog = "# Changelog\n\n## [1.0.0] - 2025-01-01\n\n- Initial release\n";
+
+		assert_eq!(
+			release(changelog, "1.0.1", date(), None).unwrap(),
+			"# Changelog\n\n## [1.0.1] - 2025-06-01\n\n## [1.0.0] - 2025-01-01\n\n- Initial release\n"
+		);
+	}
+
+	#[test]
+	fn duplicate_version() {
+		for changelog in [
+			"# Changelog\n\n## [1.0.0] - 2025-01-01\n",
+			"# Changelog\n\n## [1.0.0]\n",
+			"# Changelog\n\n## 1.0.0\n",
+			"# Changelog\n\n## 1.0.0 - 2025-01-01\n",
+		] {
+			assert!(release(changelog, "1.0.0", date(), None).is_err());
+		}
+
+		// Prereleases are different versions
+		assert!(release("# Changelog\n\n## 1.0.0-beta.1\n", "1.0.0", date(), None).is_ok());
+	}
+
+	#[test]
+	fn excerpts() {
+		let changelog = "# Changelog\n\n## [1.1.0] - 2025-06-01\n\n- Faster sync\n\n## [1.0.0-beta.1] - 2025-02-01\n\n## [1.0.0] - 2025-01-01\n\n- Initial release\n";
+
+		assert_eq!(
+			excerpt(changelog, "1.1.0").unwrap(),
+			"## [1.1.0] - 2025-06-01\n\n- Faster sync\n"
+		);
+		assert_eq!(
+			excerpt(changelog, "1.0.0").unwrap(),
+			"## [1.0.0] - 2025-01-01\n\n- Initial release\n"
+		);
+		assert!(excerpt(changelog, "2.0.0").is_none());
+	}
+}
+
+mod team {
+	use fluxo::{
+		plugin::team::{Permission, TeamConfig, User, TOKEN_VAR},
+		util,
+	};
+	use std::{env, path::Path};
+
+	fn team() -> TeamConfig {
+		serde_json::from_value(serde_json::json!({
+			"members": [
+				{ "name": "alice", "email": "alice@example.com", "permissions": ["publish", "edit-metadata"] },
+				{ "name": "bob", "permissions": ["edit-metadata"] },
+				{ "name": "ci", "permissions": ["publish"], "tokenHash": util::hash("secret") },
+			]
+		}))
+		.unwrap()
+	}
+
+	fn user(name: &str, email: Option<&str>) -> User {
+		User {
+			name: name.into(),
+			email: email.map(Into::into),
+		}
+	}
+
+	#[test]
+	fn permissions() {
+		let team = team();
+
+		assert!(team
+			.authorize(
+				&user("Alice Smith", Some("ALICE@example.com")),
+				Permission::EditMetadata
+			)
+			.is_ok());
+		assert!(team.authorize(&user("bob", None), Permission::EditMetadata).is_ok());
+
+		// Members with an email cannot be matched by the name alone
+		let error = team.authorize(&user("alice", None), Permission::Publish).unwrap_err();
+		assert!(error.to_string().contains("not a team member"));
+
+		let spoofed = user("alice", Some("eve@example.com"));
+		assert!(team.authorize(&spoofed, Permission::Publish).is_err());
+
+		let error = team.authorize(&user("bob", None), Permission::Publish).unwrap_err();
+		assert!(error.to_string().contains("publish permission"));
+
+		let error = team.authorize(&user("eve", None), Permission::Publish).unwrap_err();
+		assert!(error.to_string().contains("not a team member"));
+
+		// Nothing is enforced without members
+		assert!(TeamConfig::default()
+			.authorize(&user("eve", None), Permission::Publish)
+			.is_ok());
+	}
+
+	#[test]
+	fn act_as() {
+		let team = team();
+		let dir = Path::new(".");
+
+		env::remove_var(TOKEN_VAR);
+		assert!(team.check(dir, Some("ci"), Permission::Publish).is_err());
+
+		env::set_var(TOKEN_VAR, "wrong");
+		assert!(team.check(dir, Some("ci"), Permission::Publish).is_err());
+
+		env::set_var(TOKEN_VAR, "secret");
+		assert!(team.check(dir, Some("ci"), Permission::Publish).is_ok());
+		assert!(team.check(dir, Some("ci"), Permission::EditMetadata).is_err());
+
+		// Members without a token hash cannot be impersonated
+		assert!(team.check(dir, Some("alice"), Permission::Publish).is_err());
+		assert!(team.check(dir, Some("nobody"), Permission::Publish).is_err());
+
+		env::remove_var(TOKEN_VAR);
+	}
+}
+
+mod channel {
+	use fluxo::plugin::{channel::Channel, metadata::PluginMetadata, version::Bump};
+	use semver::Version;
+
+	fn beta() -> Channel {
+		serde_json::from_value(serde_json::json!({
+			"assetId": 1,
+			"nameSuffix": " (Beta)",
+			"prerelease": "beta",
+			"metadata": { "description": "Beta build", "tags": ["testing"] }
+		}))
+		.unwrap()
+	}
+
+	fn version(version: &str) -> Version {
+		Version::parse(version).unwrap()
+	}
+
+	#[test]
+	fn metadata() {
+		let metadata = PluginMetadata {
+			name: "Tool".into(),
+			description: "Stable build".into(),
+			version: "1.0.0".into(),
+			..Default::default()
+		};
+
+		let applied = beta().apply(&metadata).unwrap();
+
+		assert_eq!(applied.name, "Tool (Beta)");
+		assert_eq!(applied.description, "Beta build");
+		assert_eq!(applied.tags, vec!["testing"]);
+		assert_eq!(applied.version, "1.0.0");
+
+		assert_eq!(Channel::default().apply(&metadata).unwrap(), metadata);
+	}
+
+	#[test]
+	fn versions() {
+		let beta = beta();
+		let stable = Channel::default();
+
+		assert!(beta.accepts(&version("1.2.0-beta.3")));
+		assert!(!beta.accepts(&version("1.2.0-rc.1")));
+		assert!(!beta.accepts(&version("1.2.0")));
+		assert!(stable.accepts(&version("1.2.0")));
+		assert!(!stable.accepts(&version("1.2.0-beta.3")));
+
+		assert_eq!(beta.version(&version("1.2.0")).unwrap(), version("1.2.0-beta.1"));
+		assert_eq!(stable.version(&version("1.2.0-beta.3")).unwrap(), version("1.2.0"));
+		assert!(beta.version(&version("1.2.0-rc.1")).is_err());
+
+		assert_eq!(
+			Bump::Prerelease.apply_with(&version("1.2.0"), "rc").unwrap(),
+			version("1.2.1-rc.1")
+		);
+	}
+}
+
+mod icon {
+	use super::common::workspace;
+	use fluxo::{
+		plugin::{
+			icon::{self, Icon, VARIANT_SIZES},
+			metadata::PluginMetadata,
+		},
+		validation::Severity,
+	};
+	use std::fs;
+
+	fn png(width: u32, height: u32) -> Vec<u8> {
+		let mut data = Vec::new();
+
+		let mut encoder = png::Encoder::new(&mut data, width, height);
+		encoder.set_color(png::ColorType::Rgb);
+		encoder.set_depth(png::BitDepth::Eight);
+
+		let mut writer = encoder.write_header().unwrap();
+		writer
+			.write_image_data(&vec![200; (width * height * 3) as usize])
+			.unwrap();
+		writer.finish().unwrap();
+
+		data
+	}
+
+	fn severities(icon: &Icon) -> Vec<Severity> {
+		icon.validate().into_iter().map(|(severity, _)| severity).collect()
+	}
+
+	#[test]
+	fn decode() {
+		let icon = Icon::decode(&png(16, 16)).unwrap();
+
+		assert_eq!((icon.width, icon.height), (16, 16));
+		assert_eq!(Icon::decode(&icon.encode().unwrap()).unwrap().width, 16);
+
+		let resized = icon.resize(4);
+
+		assert_eq!((resized.width, resized.height), (4, 4));
+		assert_eq!(Icon::decode(&resized.encode().unwrap()).unwrap().height, 4);
+
+		assert!(Icon::decode(b"GIF89a").is_err());
+	}
+
+	#[test]
+	fn validate() {
+		let severities = |width, height| severities(&Icon::decode(&png(width, height)).unwrap());
+
+		assert_eq!(severities(512, 512), vec![]);
+		assert_eq!(severities(256, 256), vec![Severity::Warning]);
+		assert_eq!(severities(1024, 1024), vec![Severity::Info]);
+		assert_eq!(severities(512, 256), vec![Severity::Error]);
+	}
+
+	#[test]
+	fn locate() {
+		let dir = workspace("icon-locate", &[]);
+		let path = dir.as_path();
+
+		assert_eq!(icon::locate(path, None), None);
+		assert_eq!(icon::validate(path, None)[0].severity, Severity::Warning);
+
+		fs::write(path.join(icon::ICON_FILE), png(64, 64)).unwrap();
+		fs::write(path.join("custom.png"), b"not a png").unwrap();
+
+		let metadata = |icon: &str| PluginMetadata {
+			icon: Some(icon.into()),
+			..Default::default()
+		};
+
+		assert_eq!(icon::locate(path, None), Some(icon::ICON_FILE.into()));
+		assert_eq!(
+			icon::locate(path, Some(&metadata("custom.png"))),
+			Some("custom.png".into())
+		);
+		assert_eq!(icon::locate(path, Some(&metadata("rbxassetid://1"))), None);
+
+		assert_eq!(icon::validate(path, Some(&metadata("rbxassetid://1"))), vec![]);
+		assert_eq!(
+			icon::validate(path, Some(&metadata("custom.png")))[0].severity,
+			Severity::Error
+		);
+	}
+
+	#[test]
+	fn variants() {
+		let dir = workspace("icon-variants", &[]);
+		let icon = Icon::decode(&png(600, 600)).unwrap();
+
+		let paths = icon.write_variants(&dir).unwrap();
+
+		assert_eq!(paths.len(), VARIANT_SIZES.len());
+
+		for (path, size) in paths.iter().zip(VARIANT_SIZES) {
+			let variant = Icon::load(path).unwrap();
+
+			assert_eq!((variant.width, variant.height), (size, size));
+		}
+	}
+}
@@ -0,0 +1,504 @@
+use fluxo::{
+	git::{self, Commit},
+	plugin::config::Creator,
+	plugin::metadata::PluginMetadata,
+	publish::{
+		cloud::{Asset, OpenCloud, Upload},
+		ledger::{self, Entry, FileChange, Ledger, LEDGER_FILE},
+		notes,
+		package::{self, MODEL_FILE},
+		payload::{self, Payload, PAYLOAD_FILE},
+		Artifact,
+	},
+};
+use std::{
+	fs::{self, File},
+	io::{BufRead, BufReader, Read, Write},
+	net::TcpListener,
+	path::{Path, PathBuf},
+	process::Command,
+	sync::{Arc, Mutex},
+	thread,
+	time::Duration,
+};
+use zip::{write::FileOptions, ZipArchive, ZipWriter};
+
+mod common;
+
+use common::workspace;
+
+fn plugin(name: &str) -> PathBuf {
+	workspace(
+		name,
+		&[
+			("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+			("src/init.luau", "return 1"),
+			("src/Util.luau", "return {}"),
+			(
+				"plugin.meta.json",
+				r#"{"name": "Test", "description": "Test plugin", "version": "1.0.0"}"#,
+			),
+		],
+	)
+}
+
+#[test]
+fn artifact() {
+	let dir = plugin("artifact");
+	let artifact = Artifact::build(&dir.join("default.project.json")).unwrap();
+
+	assert!(!artifact.data.is_empty());
+	assert_eq!(
+		artifact.files.keys().collect::<Vec<_>>(),
+		vec![
+			"default.project.json",
+			"plugin.meta.json",
+			"src/Util.luau",
+			"src/init.luau"
+		]
+	);
+
+	let rebuilt = Artifact::build(&dir.join("default.project.json")).unwrap();
+	assert_eq!(artifact.files, rebuilt.files);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn ledger() {
+	let dir = plugin("ledger");
+	let project = dir.join("default.project.json");
+
+	let metadata = PluginMetadata::load(&dir).unwrap();
+	let artifact = Artifact::build(&project).unwrap();
+
+	let mut ledger = Ledger::load(&dir).unwrap();
+	assert!(ledger.last().is_none());
+
+	ledger.push(Entry::new(&artifact, &metadata, Some("First".into()), None));
+	ledger.save().unwrap();
+
+	assert!(dir.join(LEDGER_FILE).exists());
+
+	let ledger = Ledger::load(&dir).unwrap();
+	let entry = ledger.find("v1.0.0").unwrap();
+
+	assert_eq!(ledger.entries().len(), 1);
+	assert_eq!(entry.hash, artifact.hash);
+	assert_eq!(entry.notes.as_deref(), Some("First"));
+	assert_eq!(entry.metadata, metadata);
+	assert!(ledger.find("2.0.0").is_none());
+
+	fs::write(dir.join("src/init.luau"), "return 2").unwrap();
+	fs::write(dir.join("src/New.luau"), "return 3").unwrap();
+	fs::remove_file(dir.join("src/Util.luau")).unwrap();
+
+	let artifact = Artifact::build(&project).unwrap();
+
+	assert_eq!(
+		entry.diff(&artifact.files),
+		vec![
+			("src/New.luau".into(), FileChange::Added),
+			("src/Util.luau".into(), FileChange::Removed),
+			("src/init.luau".into(), FileChange::Modified),
+		]
+	);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn channels() {
+	let dir = plugin("channels");
+	let project = dir.join("default.project.json");
+
+	let metadata = PluginMetadata::load(&dir).unwrap();
+	let artifact = Artifact::build(&project).unwrap();
+
+	let mut ledger = Ledger::load(&dir).unwrap();
+
+	let mut beta = Entry::new(&artifact, &metadata, Some("Beta".into()), None);
+	beta.version = "1.1.0-beta.1".into();
+	beta.channel = Some("beta".into());
+
+	ledger.push(beta);
+	ledger.push(Entry::new(&artifact, &metadata, None, None));
+
+	let source = ledger.last_in("beta").unwrap().clone();
+
+	assert_eq!(source.version, "1.1.0-beta.1");
+	assert!(ledger.last_in("stable").is_none());
+
+	let stable = PluginMetadata {
+		version: "1.1.0".into(),
+		..metadata.clone()
+	};
+
+	let promoted = source.promote(&stable, "stable");
+
+	assert_eq!(promoted.version, "1.1.0");
+	assert_eq!(promoted.channel.as_deref(), Some("stable"));
+	assert_eq!(promoted.hash, source.hash);
+	assert_eq!(promoted.files, source.files);
+	assert_eq!(promoted.notes, source.notes);
+
+	// Stored artifacts are only returned while they match their hash
+	assert!(ledger::load_artifact(&dir, &artifact.hash).is_none());
+
+	ledger::store_artifact(&dir, &artifact).unwrap();
+	assert_eq!(ledger::load_artifact(&dir, &artifact.hash), Some(artifact.data.clone()));
+
+	let path = dir.join(ledger::ARTIFACTS_DIR).join(format!("{}.rbxm", artifact.hash));
+	fs::write(path, "tampered").unwrap();
+	assert!(ledger::load_artifact(&dir, &artifact.hash).is_none());
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn bundle() {
+	let dir = plugin("bundle");
+
+	fs::write(
+		dir.join("CHANGELOG.md"),
+		"# Changelog\n\n## [1.0.0] - 2025-01-01\n\n- Initial release\n\n## [0.9.0] - 2024-12-01\n",
+	)
+	.unwrap();
+
+	let output = dir.join("bundle.zip");
+	let manifest = package::create(&dir.join("default.project.json"), &output).unwrap();
+
+	assert_eq!(manifest.version, "1.0.0");
+	assert_eq!(
+		manifest.files.keys().collect::<Vec<_>>(),
+		vec!["CHANGELOG.md", "plugin.meta.json", MODEL_FILE]
+	);
+
+	let (verified, problems) = package::verify(&output).unwrap();
+
+	assert_eq!(verified, manifest);
+	assert!(problems.is_empty());
+
+	// Copy the bundle with the changelog modified
+	let mut archive = ZipArchive::new(File::open(&output).unwrap()).unwrap();
+	let tampered = dir.join("tampered.zip");
+	let mut zip = ZipWriter::new(File::create(&tampered).unwrap());
+
+	for index in 0..archive.len() {
+		let mut file = archive.by_index(index).unwrap();
+		let mut contents = Vec::new();
+		file.read_to_end(&mut contents).unwrap();
+
+		if file.name() == "CHANGELOG.md" {
+			contents.extend(b"- Sneaky change\n");
+		}
+
+		zip.start_file(file.name(), FileOptions::default()).unwrap();
+		zip.write_all(&contents).unwrap();
+	}
+
+	zip.finish().unwrap();
+
+	let (_, problems) = package::verify(&tampered).unwrap();
+	assert_eq!(problems, vec!["Checksum of CHANGELOG.md does not match"]);
+
+	assert!(package::verify(&dir.join("CHANGELOG.md")).is_err());
+
+	// Icons are bundled under their own path, which must not leave the bundle
+	fs::write(dir.join("icon.png"), "").unwrap();
+	fs::write(
+		dir.join("plugin.meta.json"),
+		r#"{"name": "Test", "description": "Test plugin", "version": "1.0.0", "icon": "src/../icon.png"}"#,
+	)
+	.unwrap();
+
+	let error = package::create(&dir.join("default.project.json"), &output).unwrap_err();
+	assert!(error.to_string().contains("inside the project"));
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn dry_run() {
+	let dir = plugin("dry-run");
+	let project = dir.join("default.project.json");
+
+	let metadata = PluginMetadata::load(&dir).unwrap();
+	let artifact = Artifact::build(&project).unwrap();
+	let payload = Payload::new(&artifact, &metadata, Some("Notes".into()));
+
+	assert_eq!(payload.size, artifact.data.len());
+	assert_eq!(payload.files["src/init.luau"].size, 8);
+	assert_eq!(payload.hashes(), artifact.files);
+	assert_eq!(payload.request()["notes"], "Notes");
+	assert_eq!(payload.request()["hash"], artifact.hash.as_str());
+
+	let output = dir.join(".fluxo/dry-run");
+	let path = payload.save(&output, &artifact.data).unwrap();
+
+	assert_eq!(path, output.join(PAYLOAD_FILE));
+	assert_eq!(fs::read(output.join(MODEL_FILE)).unwrap(), artifact.data);
+	assert_eq!(Payload::load(&output).unwrap(), payload);
+
+	// Previous publish only differs in version and the source of `Util`
+	let mut previous = Payload::from(&Entry::new(&artifact, &metadata, None, None));
+
+	previous.metadata.version = "0.9.0".into();
+	previous.metadata.author = Some("Someone".into());
+	previous.files.get_mut("src/Util.luau").unwrap().hash = "old".into();
+
+	let changes = payload::diff_metadata(&previous.metadata, &payload.metadata);
+
+	assert_eq!(
+		changes.iter().map(|change| change.field.as_str()).collect::<Vec<_>>(),
+		vec!["author", "version"]
+	);
+	assert_eq!(changes[0].new, None);
+	assert_eq!(changes[1].old, Some("0.9.0".into()));
+	assert_eq!(changes[1].new, Some("1.0.0".into()));
+
+	assert_eq!(
+		ledger::diff_files(&previous.hashes(), &payload.hashes()),
+		vec![("src/Util.luau".into(), FileChange::Modified)]
+	);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+fn git_commit(dir: &Path, message: &str) -> String {
+	let git = |args: &[&str]| {
+		let output = Command::new("git")
+			.args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+			.args(args)
+			.current_dir(dir)
+			.output()
+			.unwrap();
+
+		assert!(output.status.success());
+		String::from_utf8(output.stdout).unwrap().trim().to_owned()
+	};
+
+	fs::write(dir.join("log.txt"), message).unwrap();
+
+	git(&["add", "-A"]);
+	git(&["commit", "-q", "-m", message]);
+	git(&["rev-parse", "HEAD"])
+}
+
+#[test]
+fn release_notes() {
+	let commit = |subject: &str, body: &str| Commit {
+		hash: "abc1234".into(),
+		subject: subject.into(),
+		body: body.into(),
+	};
+
+	let commits = vec![
+		commit("chore: bump deps", ""),
+		commit("fix(sync): handle empty files", ""),
+		commit("feat: add icons", "BREAKING CHANGE: icons are required"),
+		commit("Update readme", ""),
+		commit("feat(cli)!: drop --legacy", ""),
+		commit("feat: faster builds", ""),
+	];
+
+	assert_eq!(
+		notes::generate(&commits).unwrap(),
+		"### Breaking Changes\n\n- add icons (abc1234)\n- **cli:** drop --legacy (abc1234)\n\n\
+		### Features\n\n- faster builds (abc1234)\n\n\
+		### Bug Fixes\n\n- **sync:** handle empty files (abc1234)\n\n\
+		### Chores\n\n- bump deps (abc1234)\n\n\
+		### Other Changes\n\n- Update readme (abc1234)"
+	);
+	assert!(notes::generate(&[]).is_none());
+
+	// Notes start at the last tag or publish, whichever is more recent
+	let dir = plugin("release-notes");
+
+	Command::new("git")
+		.args(["init", "-q"])
+		.current_dir(&dir)
+		.status()
+		.unwrap();
+
+	git_commit(&dir, "feat: initial");
+	Command::new("git")
+		.args(["tag", "v1.0.0"])
+		.current_dir(&dir)
+		.status()
+		.unwrap();
+
+	let published = git_commit(&dir, "fix: first");
+	git_commit(&dir, "fix: second");
+
+	assert_eq!(notes::base(&dir, None).unwrap().as_deref(), Some("v1.0.0"));
+	assert_eq!(notes::base(&dir, Some(&published)).unwrap(), Some(published.clone()));
+	assert_eq!(notes::base(&dir, Some("0000000")).unwrap().as_deref(), Some("v1.0.0"));
+
+	let commits = git::commits(&dir, Some(&published)).unwrap();
+
+	assert_eq!(commits.len(), 1);
+	assert_eq!(commits[0].subject, "fix: second");
+	assert_eq!(git::commits(&dir, Some("v1.0.0")).unwrap().len(), 2);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+/// Method, path and body of every received request
+type Requests = Arc<Mutex<Vec<(String, String, String)>>>;
+
+/// Minimal Open Cloud assets API, returns its URL and the received requests
+fn mock_open_cloud() -> (String, Requests) {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let url = format!("http://{}", listener.local_addr().unwrap());
+
+	let requests = Arc::new(Mutex::new(Vec::new()));
+	let received = requests.clone();
+
+	thread::spawn(move || {
+		let mut polls = 0;
+
+		for stream in listener.incoming() {
+			let mut stream = stream.unwrap();
+			let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+			let mut line = String::new();
+			reader.read_line(&mut line).unwrap();
+
+			let mut parts = line.split_whitespace();
+			let method = parts.next().unwrap().to_owned();
+			let path = parts.next().unwrap().to_owned();
+
+			let mut length = 0;
+			let mut api_key = String::new();
+
+			loop {
+				let mut header = String::new();
+				reader.read_line(&mut header).unwrap();
+
+				if header.trim().is_empty() {
+					break;
+				}
+
+				let (name, value) = header.split_once(':').unwrap();
+
+				match name.to_lowercase().as_str() {
+					"content-length" => length = value.trim().parse().unwrap(),
+					"x-api-key" => api_key = value.trim().to_owned(),
+					_ => {}
+				}
+			}
+
+			let mut body = vec![0; length];
+			reader.read_exact(&mut body).unwrap();
+
+			received.lock().unwrap().push((
+				method.clone(),
+				path.clone(),
+				String::from_utf8_lossy(&body).into_owned(),
+			));
+
+			let (status, response) = match (api_key.as_str(), method.as_str(), path.as_str()) {
+				(key, _, _) if key != "test-key" => ("401 Unauthorized", r#"{"message": "Invalid API key"}"#),
+				(_, "POST", "/assets/v1/assets") => ("200 OK", r#"{"path": "operations/create", "done": false}"#),
+				(_, "GET", "/assets/v1/operations/create") => {
+					polls += 1;
+
+					if polls < 2 {
+						("200 OK", r#"{"path": "operations/create", "done": false}"#)
+					} else {
+						(
+							"200 OK",
+							r#"{"path": "operations/create", "done": true, "response": {"assetId": "123", "revisionId": "1"}}"#,
+						)
+					}
+				}
+				(_, "PATCH", "/assets/v1/assets/123") => (
+					"200 OK",
+					r#"{"path": "operations/update", "done": true, "response": {"assetId": "123", "revisionId": "2"}}"#,
+				),
+				_ => ("404 Not Found", r#"{"message": "Not found"}"#),
+			};
+
+			write!(
+				stream,
+				"HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+				status,
+				response.len(),
+				response
+			)
+			.unwrap();
+		}
+	});
+
+	(url, requests)
+}
+
+#[test]
+fn open_cloud() {
+	let (url, requests) = mock_open_cloud();
+
+	let cloud = OpenCloud::new("test-key")
+		.with_api_url(&url)
+		.with_poll_interval(Duration::from_millis(10));
+
+	let mut upload = Upload {
+		asset_id: None,
+		creator: Some(Creator::UserId(42)),
+		name: "Test",
+		description: "Test plugin",
+		data: b"<roblox!model data>",
+	};
+
+	assert_eq!(
+		cloud.upload(&upload).unwrap(),
+		Asset {
+			id: 123,
+			version: Some(1)
+		}
+	);
+
+	upload.asset_id = Some(123);
+
+	assert_eq!(
+		cloud.upload(&upload).unwrap(),
+		Asset {
+			id: 123,
+			version: Some(2)
+		}
+	);
+
+	let requests = requests.lock().unwrap().clone();
+	let routes: Vec<_> = requests
+		.iter()
+		.map(|(method, path, _)| format!("{} {}", method, path))
+		.collect();
+
+	assert_eq!(
+		routes,
+		vec![
+			"POST /assets/v1/assets",
+			"GET /assets/v1/operations/create",
+			"GET /assets/v1/operations/create",
+			"PATCH /assets/v1/assets/123",
+		]
+	);
+
+	let body = &requests[0].2;
+
+	assert!(body.contains(r#""creator":{"userId":"42"}"#));
+	assert!(body.contains("Content-Type: model/x-rbxm"));
+	assert!(body.contains("<roblox!model data>"));
+
+	let error = OpenCloud::new("wrong-key")
+		.with_api_url(&url)
+		.upload(&upload)
+		.unwrap_err();
+
+	assert!(error.to_string().contains("Invalid API key"));
+
+	upload.asset_id = None;
+	upload.creator = None;
+
+	assert!(cloud.upload(&upload).is_err());
+}
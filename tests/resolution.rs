@@ -1,5 +1,5 @@
 mod unresolved_value {
-	use argon::resolution::UnresolvedValue;
+	use fluxo::resolution::UnresolvedValue;
 
 	use rbx_dom_weak::types::{
 		Attributes, Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence, ColorSequenceKeypoint,
@@ -387,7 +387,7 @@ mod unresolved_value {
 mod resolved_value {
 
 	use approx::assert_relative_eq;
-	use argon::resolution::UnresolvedValue;
+	use fluxo::resolution::UnresolvedValue;
 
 	use rbx_dom_weak::types::{
 		Attributes, Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence, ColorSequenceKeypoint,
@@ -0,0 +1,533 @@
+use fluxo::{
+	core::Core,
+	project::Project,
+	publish::ledger::FileChange,
+	server::{self, Message, Server, SyncChanges},
+	sync::{ChangesRequest, Manifest, SyncRequest, SyncResponse},
+};
+use reqwest::{blocking::Client, header::CONTENT_TYPE};
+use serde::Serialize;
+use serde_json::Value;
+use std::{fs, sync::Arc, thread, time::Duration};
+
+mod common;
+
+use common::workspace;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscribeRequest {
+	client_id: u32,
+	name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReadRequest {
+	client_id: u32,
+}
+
+#[derive(Serialize)]
+struct ExecRequest {
+	code: String,
+	focus: bool,
+	token: Option<String>,
+}
+
+fn post(address: &str, endpoint: &str, body: &impl Serialize) -> reqwest::Result<Vec<u8>> {
+	let response = Client::new()
+		.post(format!("{}/{}", address, endpoint))
+		.header(CONTENT_TYPE, "application/msgpack")
+		.body(rmp_serde::to_vec_named(body).unwrap())
+		.send()?
+		.error_for_status()?;
+
+	Ok(response.bytes()?.to_vec())
+}
+
+/// Read messages of client 1 until one of the `name` type arrives
+fn read_message(address: &str, name: &str) -> rmpv::Value {
+	for _ in 0..20 {
+		let message = post(address, "read", &ReadRequest { client_id: 1 }).unwrap();
+		let message: rmpv::Value = rmp_serde::from_slice(&message).unwrap();
+
+		let value = message
+			.as_map()
+			.and_then(|map| map.iter().find(|(key, _)| key.as_str() == Some(name)));
+
+		if let Some((_, value)) = value {
+			return value.clone();
+		}
+	}
+
+	panic!("No {} message received", name);
+}
+
+/// Read messages until one of the `Diagnostics` type arrives
+fn read_diagnostics(address: &str) -> Vec<String> {
+	// Other messages may contain binary data that JSON cannot represent
+	let report: Value = serde_json::to_value(read_message(address, "Diagnostics")).unwrap();
+
+	report["diagnostics"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|diagnostic| diagnostic["message"].as_str().unwrap().to_owned())
+		.collect()
+}
+
+#[test]
+fn live_validation() {
+	let dir = workspace(
+		"live",
+		&[
+			("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+			("src/init.luau", "loadstring(\"\")"),
+			(
+				"plugin.meta.json",
+				r#"{"name": "Test", "description": "Test plugin", "version": "1.0.0", "icon": "rbxassetid://1"}"#,
+			),
+			("fluxo.config.json", "{}"),
+		],
+	);
+
+	let project = Project::load(&dir.join("default.project.json")).unwrap();
+	let core = Arc::new(Core::new(project, true).unwrap());
+
+	core.processor().enable_validation();
+
+	let port = server::get_free_port("localhost", 8200);
+	let address = server::format_address("localhost", port);
+
+	thread::spawn(move || Server::new(core, "localhost", port).start());
+
+	let subscribe = SubscribeRequest {
+		client_id: 1,
+		name: String::from("Test"),
+	};
+
+	// Wait for the server to start
+	for attempt in 0.. {
+		match post(&address, "subscribe", &subscribe) {
+			Ok(_) => break,
+			Err(_) if attempt < 50 => thread::sleep(Duration::from_millis(100)),
+			Err(err) => panic!("{}", err),
+		}
+	}
+
+	// Existing problems are sent right after subscribing
+	assert_eq!(read_diagnostics(&address), vec!["Banned API 'loadstring' is used"]);
+
+	// VFS ignores changes made right after it starts watching
+	thread::sleep(Duration::from_millis(500));
+	fs::write(dir.join("src/init.luau"), "getfenv()").unwrap();
+
+	assert_eq!(read_diagnostics(&address), vec!["Banned API 'getfenv' is used"]);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+fn post_json(address: &str, endpoint: &str, token: &str, body: &impl Serialize) -> SyncResponse {
+	Client::new()
+		.post(format!("{}/{}", address, endpoint))
+		.header(server::TOKEN_HEADER, token)
+		.json(body)
+		.send()
+		.unwrap()
+		.json()
+		.unwrap()
+}
+
+#[test]
+fn sync_changes() {
+	let dir = workspace(
+		"sync",
+		&[
+			("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+			("src/init.luau", "return 0"),
+			("src/One.luau", "return 1"),
+		],
+	);
+
+	let project_path = dir.join("default.project.json");
+
+	// Server does not watch the files, so clients only get what `fluxo sync` sends
+	let core = Arc::new(Core::new(Project::load(&project_path).unwrap(), false).unwrap());
+
+	let port = server::get_free_port("localhost", 8300);
+	let address = server::format_address("localhost", port);
+
+	let server = Server::new(core, "localhost", port);
+	let token = server.token().to_owned();
+
+	thread::spawn(move || server.start());
+
+	// Wait for the server to start
+	for attempt in 0.. {
+		match reqwest::blocking::get(format!("{}/health", address)) {
+			Ok(_) => break,
+			Err(_) if attempt < 50 => thread::sleep(Duration::from_millis(100)),
+			Err(err) => panic!("{}", err),
+		}
+	}
+
+	// JSON endpoints and the msgpack protocol share one port
+	let subscribe = SubscribeRequest {
+		client_id: 1,
+		name: String::from("Test"),
+	};
+
+	assert!(post(&address, "subscribe", &subscribe).is_ok());
+
+	let report: Value = Client::new()
+		.post(format!("{}/validate", address))
+		.send()
+		.unwrap()
+		.json()
+		.unwrap();
+
+	assert!(report["summary"].is_object());
+
+	let core = Core::new(Project::load(&project_path).unwrap(), true).unwrap();
+
+	let queue = core.queue();
+	queue.subscribe_internal().unwrap();
+
+	let full = SyncRequest::new(&core).unwrap();
+	let mut manifest = Manifest::new(&core);
+
+	assert!(post_json(&address, "sync", &token, &full).success);
+
+	// Subscribed clients receive the whole tree
+	let snapshot = read_message(&address, "SyncSnapshot");
+	let snapshot = snapshot.as_map().unwrap();
+	let field = |name: &str| {
+		snapshot
+			.iter()
+			.find(|(key, _)| key.as_str() == Some(name))
+			.unwrap()
+			.1
+			.clone()
+	};
+
+	assert_eq!(field("name").as_str(), Some("Test"));
+	assert_eq!(field("class").as_str(), Some("ModuleScript"));
+	assert_eq!(field("children").as_array().unwrap().len(), 1);
+
+	// VFS ignores changes made right after it starts watching
+	thread::sleep(Duration::from_millis(500));
+	fs::write(dir.join("src/One.luau"), "return 2").unwrap();
+
+	let Message::SyncChanges(SyncChanges(mut changes)) = queue.get_change(0).unwrap() else {
+		panic!("Expected changes");
+	};
+
+	// Updates of instances whose files did not change are not sent again
+	let mut unchanged = changes.clone();
+
+	let files = manifest.update(&core, &mut changes);
+	assert_eq!(files, vec![(String::from("src/One.luau"), FileChange::Modified)]);
+
+	assert!(manifest.update(&core, &mut unchanged).is_empty());
+	assert!(unchanged.is_empty());
+
+	let request = ChangesRequest::new(&core, full.hash.clone(), changes).unwrap();
+
+	assert_eq!(request.changes.updates.len(), 1);
+	assert!(request.changes.additions.is_empty() && request.changes.removals.is_empty());
+	assert_ne!(request.hash, full.hash);
+
+	let response = post_json(&address, "sync/changes", &token, &request);
+	assert!(response.success && !response.resync);
+
+	// And then only the changes
+	let changes: Value = serde_json::to_value(read_message(&address, "SyncChanges")).unwrap();
+
+	assert_eq!(changes["updates"].as_array().unwrap().len(), 1);
+	assert!(changes["additions"].as_array().unwrap().is_empty());
+
+	// The client already moved past the previous hash, so it asks for everything
+	let stale = ChangesRequest::new(&core, full.hash, request.changes).unwrap();
+	assert!(post_json(&address, "sync/changes", &token, &stale).resync);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn session_token() {
+	let dir = workspace(
+		"token",
+		&[
+			("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+			("src/init.luau", "return 0"),
+		],
+	);
+
+	let project = Project::load(&dir.join("default.project.json")).unwrap();
+	let core = Arc::new(Core::new(project, false).unwrap());
+
+	let port = server::get_free_port("localhost", 8400);
+	let address = server::format_address("localhost", port);
+
+	let server = Server::new(core.clone(), "localhost", port);
+	let token = server.token().to_owned();
+
+	// Every server gets its own secret
+	assert_ne!(token, Server::new(core, "localhost", port).token());
+
+	thread::spawn(move || server.start());
+
+	for attempt in 0.. {
+		match reqwest::blocking::get(format!("{}/health", address)) {
+			Ok(_) => break,
+			Err(_) if attempt < 50 => thread::sleep(Duration::from_millis(100)),
+			Err(err) => panic!("{}", err),
+		}
+	}
+
+	let subscribe = SubscribeRequest {
+		client_id: 1,
+		name: String::from("Test"),
+	};
+
+	assert!(post(&address, "subscribe", &subscribe).is_ok());
+
+	let exec = |token: Option<&str>| ExecRequest {
+		code: String::from("print(1)"),
+		focus: false,
+		token: token.map(str::to_owned),
+	};
+
+	let status = |error: reqwest::Error| error.status().unwrap();
+
+	// Token is taken from the msgpack body
+	assert_eq!(status(post(&address, "exec", &exec(None)).unwrap_err()), 401);
+	assert_eq!(status(post(&address, "exec", &exec(Some("wrong"))).unwrap_err()), 401);
+	assert!(post(&address, "exec", &exec(Some(&token))).is_ok());
+
+	// Or from the header
+	let response = Client::new()
+		.post(format!("{}/exec", address))
+		.header(CONTENT_TYPE, "application/msgpack")
+		.header(server::TOKEN_HEADER, &token)
+		.body(rmp_serde::to_vec_named(&exec(None)).unwrap())
+		.send()
+		.unwrap();
+
+	assert!(response.status().is_success());
+
+	// Stopping without the token would end this test otherwise
+	let response = Client::new().post(format!("{}/stop", address)).send().unwrap();
+	assert_eq!(response.status(), 401);
+
+	let response = Client::new()
+		.post(format!("{}/sync", address))
+		.json(&serde_json::json!({}))
+		.send()
+		.unwrap();
+
+	assert_eq!(response.status(), 401);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn publish_hash() {
+	let dir = workspace(
+		"publish",
+		&[
+			("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+			("src/init.luau", "return 0"),
+		],
+	);
+
+	let project = dir.join("default.project.json");
+	let core = Arc::new(Core::new(Project::load(&project).unwrap(), false).unwrap());
+
+	// Client core stands in for `fluxo sync`
+	let client = Core::new(Project::load(&project).unwrap(), false).unwrap();
+	let request = SyncRequest::new(&client).unwrap();
+
+	let port = server::get_free_port("localhost", 8600);
+	let address = server::format_address("localhost", port);
+
+	let server = Server::new(core, "localhost", port);
+	let token = server.token().to_owned();
+
+	thread::spawn(move || server.start());
+
+	for attempt in 0.. {
+		match reqwest::blocking::get(format!("{}/health", address)) {
+			Ok(_) => break,
+			Err(_) if attempt < 50 => thread::sleep(Duration::from_millis(100)),
+			Err(err) => panic!("{}", err),
+		}
+	}
+
+	let publish = |hash: &str| -> Value {
+		Client::new()
+			.post(format!("{}/publish", address))
+			.header(server::TOKEN_HEADER, &token)
+			.json(&serde_json::json!({ "metadata": {}, "hash": hash }))
+			.send()
+			.unwrap()
+			.json()
+			.unwrap()
+	};
+
+	// Nothing to compare against before the first sync
+	let response = publish(&request.hash);
+
+	assert_eq!(response["success"], false);
+	assert!(response["error"].as_str().unwrap().contains("fluxo sync"));
+
+	let hash = request.hash.clone();
+	assert!(post_json(&address, "sync", &token, &request).success);
+
+	assert_eq!(publish(&hash)["success"], true);
+
+	// Studio would publish a different plugin than the one that was built
+	let response = publish(&"0".repeat(64));
+
+	assert_eq!(response["success"], false);
+	let error = response["error"].as_str().unwrap();
+
+	assert!(error.contains("sync the published sources"));
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+type Socket = tungstenite::WebSocket<tungstenite::stream::MaybeTlsStream<std::net::TcpStream>>;
+
+fn connect(address: &str, query: &str, token: Option<&str>) -> Socket {
+	use tungstenite::client::IntoClientRequest;
+
+	let url = format!("{}/socket?{}", address.replace("http://", "ws://"), query);
+	let mut request = url.into_client_request().unwrap();
+
+	if let Some(token) = token {
+		request
+			.headers_mut()
+			.insert(server::TOKEN_HEADER, token.parse().unwrap());
+	}
+
+	let (socket, _) = tungstenite::connect(request).unwrap();
+
+	if let tungstenite::stream::MaybeTlsStream::Plain(stream) = socket.get_ref() {
+		stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+	}
+
+	socket
+}
+
+/// Returns the type of the next message streamed from the queue
+fn next_message(socket: &mut Socket) -> String {
+	loop {
+		if let tungstenite::Message::Binary(data) = socket.read().unwrap() {
+			let message: rmpv::Value = rmp_serde::from_slice(&data).unwrap();
+			return message.as_map().unwrap()[0].0.as_str().unwrap().to_owned();
+		}
+	}
+}
+
+#[test]
+fn socket() {
+	let dir = workspace(
+		"socket",
+		&[
+			("default.project.json", r#"{"name": "Test", "tree": {"$path": "src"}}"#),
+			("src/init.luau", "loadstring(\"\")"),
+			("fluxo.config.json", "{}"),
+		],
+	);
+
+	let project = Project::load(&dir.join("default.project.json")).unwrap();
+	let core = Arc::new(Core::new(project, true).unwrap());
+
+	core.processor().enable_validation();
+
+	let port = server::get_free_port("localhost", 8500);
+	let address = server::format_address("localhost", port);
+
+	let server = Server::new(core, "localhost", port);
+	let token = server.token().to_owned();
+
+	thread::spawn(move || server.start());
+
+	for client_id in [1, 2, 3] {
+		let subscribe = SubscribeRequest {
+			client_id,
+			name: String::from("Test"),
+		};
+
+		for attempt in 0.. {
+			match post(&address, "subscribe", &subscribe) {
+				Ok(_) => break,
+				Err(_) if attempt < 50 => thread::sleep(Duration::from_millis(100)),
+				Err(err) => panic!("{}", err),
+			}
+		}
+	}
+
+	// Only subscribed clients can connect
+	assert!(tungstenite::connect(format!("{}/socket?clientId=4", address.replace("http://", "ws://"))).is_err());
+
+	let mut socket = connect(&address, "clientId=1", Some(&token));
+
+	// Messages queued before connecting come first
+	assert_eq!(next_message(&mut socket), "Diagnostics");
+
+	// VFS ignores changes made right after it starts watching
+	thread::sleep(Duration::from_millis(500));
+	fs::write(dir.join("src/init.luau"), "return 1").unwrap();
+
+	assert_eq!(next_message(&mut socket), "SyncChanges");
+
+	let write = |client_id: u32| {
+		let write = serde_json::json!({
+			"changes": {"additions": [], "updates": [], "removals": []},
+			"clientId": client_id,
+		});
+
+		tungstenite::Message::Binary(rmp_serde::to_vec_named(&write).unwrap())
+	};
+
+	// Connection stays open after an authorized write
+	socket.send(write(1)).unwrap();
+	socket.send(tungstenite::Message::Ping(vec![1, 2])).unwrap();
+
+	loop {
+		match socket.read().unwrap() {
+			tungstenite::Message::Pong(data) => break assert_eq!(data, vec![1, 2]),
+			tungstenite::Message::Binary(_) => continue,
+			message => panic!("Unexpected message: {:?}", message),
+		}
+	}
+
+	// Writes are rejected when the token was not sent with the handshake header or the request,
+	// tokens in the URL are ignored so they do not end up in logs
+	let mut unauthorized = connect(&address, &format!("clientId=2&token={}", token), None);
+
+	unauthorized.send(write(2)).unwrap();
+
+	loop {
+		match unauthorized.read().unwrap() {
+			tungstenite::Message::Close(frame) => {
+				break assert_eq!(
+					frame.unwrap().code,
+					tungstenite::protocol::frame::coding::CloseCode::Policy
+				)
+			}
+			tungstenite::Message::Binary(_) => continue,
+			message => panic!("Unexpected message: {:?}", message),
+		}
+	}
+
+	// Long polling keeps working for clients that do not use the socket
+	let message = post(&address, "read", &ReadRequest { client_id: 3 }).unwrap();
+	let message: rmpv::Value = rmp_serde::from_slice(&message).unwrap();
+
+	assert_eq!(message.as_map().unwrap()[0].0.as_str(), Some("Diagnostics"));
+
+	fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,906 @@
+mod common;
+
+mod lexer {
+	use fluxo::validation::lexer::{tokenize, TokenKind};
+
+	fn kinds(source: &str) -> Vec<(TokenKind, String)> {
+		tokenize(source)
+			.into_iter()
+			.map(|token| (token.kind, token.value))
+			.collect()
+	}
+
+	#[test]
+	fn positions() {
+		let tokens = tokenize("local a = 1\n  print(a)");
+
+		assert_eq!((tokens[0].line, tokens[0].column), (1, 1));
+		assert_eq!((tokens[4].line, tokens[4].column), (2, 3));
+		assert_eq!((tokens[5].line, tokens[5].column), (2, 8));
+	}
+
+	#[test]
+	fn comments() {
+		assert_eq!(
+			kinds("-- hello\n--[==[ long ]] comment ]==] x"),
+			vec![
+				(TokenKind::Comment, " hello".into()),
+				(TokenKind::Comment, " long ]] comment ".into()),
+				(TokenKind::Name, "x".into()),
+			]
+		);
+	}
+
+	#[test]
+	fn strings() {
+		assert_eq!(
+			kinds(r#"'a\'b' "c" [[d]] [=[e]=]"#),
+			vec![
+				(TokenKind::String, r"a\'b".into()),
+				(TokenKind::String, "c".into()),
+				(TokenKind::String, "d".into()),
+				(TokenKind::String, "e".into()),
+			]
+		);
+	}
+
+	#[test]
+	fn interpolated_strings() {
+		assert_eq!(
+			kinds("`a{ {b} }c`"),
+			vec![
+				(TokenKind::InterpolatedString, "a".into()),
+				(TokenKind::Symbol, "{".into()),
+				(TokenKind::Name, "b".into()),
+				(TokenKind::Symbol, "}".into()),
+				(TokenKind::InterpolatedString, "c".into()),
+			]
+		);
+	}
+
+	#[test]
+	fn symbols() {
+		assert_eq!(
+			kinds("a..b...c.d"),
+			vec![
+				(TokenKind::Name, "a".into()),
+				(TokenKind::Symbol, "..".into()),
+				(TokenKind::Name, "b".into()),
+				(TokenKind::Symbol, "...".into()),
+				(TokenKind::Name, "c".into()),
+				(TokenKind::Symbol, ".".into()),
+				(TokenKind::Name, "d".into()),
+			]
+		);
+	}
+}
+
+mod banned_apis {
+	use fluxo::validation::banned_apis::{self, DEFAULT_BANNED_APIS};
+
+	fn find(source: &str) -> Vec<(&str, usize, usize)> {
+		banned_apis::find(source, &DEFAULT_BANNED_APIS)
+			.into_iter()
+			.map(|found| (found.api, found.line, found.column))
+			.collect()
+	}
+
+	#[test]
+	fn calls() {
+		assert_eq!(find("loadstring('print(1)')()"), vec![("loadstring", 1, 1)]);
+		assert_eq!(find("local env = getfenv()"), vec![("getfenv", 1, 13)]);
+		assert_eq!(
+			find("setfenv(1, {})\nsetfenv{}"),
+			vec![("setfenv", 1, 1), ("setfenv", 2, 1)]
+		);
+		assert_eq!(find("f(getfenv \"x\")"), vec![("getfenv", 1, 3)]);
+	}
+
+	#[test]
+	fn indexes() {
+		assert_eq!(find("local up = debug.getupvalue"), vec![("debug.getupvalue", 1, 12)]);
+		assert_eq!(find("debug['getupvalue'](f, 1)"), vec![("debug.getupvalue", 1, 1)]);
+		assert_eq!(find("getfenv(2).script"), vec![("getfenv", 1, 1)]);
+	}
+
+	#[test]
+	fn false_positives() {
+		assert!(find("-- loadstring(code)").is_empty());
+		assert!(find("--[[ getfenv() ]]").is_empty());
+		assert!(find("print('loadstring(x)')").is_empty());
+		assert!(find("print(`loadstring`)").is_empty());
+		assert!(find("myloadstringHelper(x)").is_empty());
+		assert!(find("module.loadstring(x)").is_empty());
+		assert!(find("local function getfenv() end").is_empty());
+		assert!(find("local t = { loadstring = true }").is_empty());
+		assert!(find("debug.traceback()").is_empty());
+		assert!(find("debug..getupvalue").is_empty());
+	}
+
+	#[test]
+	fn interpolated_expressions() {
+		assert_eq!(find("print(`{loadstring(x)}`)"), vec![("loadstring", 1, 9)]);
+	}
+}
+
+mod rules {
+	use fluxo::{plugin::config::ValidationConfig, validation::rules::RuleSet};
+	use std::path::Path;
+
+	fn rules(config: &str) -> RuleSet {
+		let config: ValidationConfig = serde_json::from_str(config).unwrap();
+		RuleSet::new(&config).unwrap()
+	}
+
+	fn check(rules: &RuleSet, path: &str, source: &str) -> Vec<(String, usize, usize)> {
+		rules
+			.check(Path::new(path), source)
+			.into_iter()
+			.map(|diagnostic| (diagnostic.rule, diagnostic.line.unwrap(), diagnostic.column.unwrap()))
+			.collect()
+	}
+
+	#[test]
+	fn banned_apis() {
+		let rules = rules(r#"{"bannedApis": ["require"]}"#);
+
+		assert_eq!(check(&rules, "src/a.luau", "loadstring(x)"), vec![]);
+		assert_eq!(
+			check(&rules, "src/a.luau", "require(x)"),
+			vec![("banned-api".into(), 1, 1)]
+		);
+	}
+
+	#[test]
+	fn custom_rules() {
+		let rules = rules(
+			r#"{
+				"bannedApis": [],
+				"customRules": [
+					{"id": "no-wait", "message": "Use task.wait", "regex": "\\bwait\\("},
+					{"id": "no-http", "message": "No HTTP", "severity": "error", "pattern": "game : GetService ( \"HttpService\" )"},
+					{"id": "no-print", "message": "No print", "pattern": "print ( $_ )", "exclude": ["src/debug/**"]}
+				]
+			}"#,
+		);
+
+		assert_eq!(
+			check(&rules, "src/a.luau", "local a = 1\n  wait(1) await(1)"),
+			vec![("no-wait".into(), 2, 3)]
+		);
+		assert_eq!(
+			check(&rules, "src/a.luau", "local h = game:GetService('HttpService')"),
+			vec![("no-http".into(), 1, 11)]
+		);
+		assert_eq!(
+			check(&rules, "src/a.luau", "print(1) print(1, 2)"),
+			vec![("no-print".into(), 1, 1)]
+		);
+		assert_eq!(check(&rules, "src/debug/a.luau", "print(1)"), vec![]);
+	}
+
+	#[test]
+	fn path_globs() {
+		let rules = rules(r#"{"include": ["src/**"], "exclude": ["src/vendor/**"]}"#);
+
+		assert!(rules.is_included(Path::new("src/a.luau")));
+		assert!(!rules.is_included(Path::new("lib/a.luau")));
+		assert!(!rules.is_included(Path::new("src/vendor/a.luau")));
+	}
+
+	#[test]
+	fn suppressions() {
+		let rules = rules(r#"{"customRules": [{"id": "no-wait", "message": "", "regex": "wait\\("}]}"#);
+
+		assert_eq!(
+			check(&rules, "a.luau", "loadstring(wait()) -- fluxo-ignore: banned-api"),
+			vec![("no-wait".into(), 1, 12)]
+		);
+		assert_eq!(check(&rules, "a.luau", "-- fluxo-ignore\nloadstring(x)"), vec![]);
+		assert_eq!(
+			check(&rules, "a.luau", "-- fluxo-ignore: no-wait\n\nloadstring(x)"),
+			vec![("banned-api".into(), 3, 1)]
+		);
+		assert_eq!(
+			check(&rules, "a.luau", "-- fluxo-ignore-file: banned-api\nx()\nloadstring(x)"),
+			vec![]
+		);
+
+		// Directive without rules suppresses everything, even with others for the same line
+		assert_eq!(
+			check(
+				&rules,
+				"a.luau",
+				"-- fluxo-ignore\nloadstring(wait()) -- fluxo-ignore: no-wait"
+			),
+			vec![]
+		);
+		assert_eq!(
+			check(
+				&rules,
+				"a.luau",
+				"-- fluxo-ignore-file: no-wait\n-- fluxo-ignore-file\nloadstring(wait())"
+			),
+			vec![]
+		);
+	}
+
+	#[test]
+	fn invalid_rules() {
+		let invalid = [
+			r#"{"customRules": [{"id": "a", "message": ""}]}"#,
+			r#"{"customRules": [{"id": "a", "message": "", "regex": "("}]}"#,
+			r#"{"customRules": [{"id": "banned-api", "message": "", "regex": "a"}]}"#,
+		];
+
+		for config in invalid {
+			let config: ValidationConfig = serde_json::from_str(config).unwrap();
+			assert!(RuleSet::new(&config).is_err());
+		}
+	}
+}
+
+mod report {
+	use fluxo::validation::{
+		format::{self, OutputFormat},
+		report::Report,
+		Diagnostic, Severity,
+	};
+	use std::path::Path;
+
+	fn report() -> Report {
+		Report::new(vec![
+			Diagnostic::new("metadata", Severity::Warning, "Plugin description is empty"),
+			Diagnostic::new("banned-api", Severity::Error, "Banned API 'loadstring' is used")
+				.with_path(Path::new("src/init.luau"))
+				.with_location(2, 11),
+			Diagnostic::new("suggestion", Severity::Info, "Add a README"),
+		])
+	}
+
+	#[test]
+	fn summary() {
+		let report = report();
+
+		assert!(!report.success);
+		assert_eq!(
+			(report.summary.errors, report.summary.warnings, report.summary.infos),
+			(1, 1, 1)
+		);
+		assert_eq!(report.diagnostics[0].rule, "banned-api");
+		assert_eq!(report.count_at_least(Severity::Warning), 2);
+		assert_eq!(report.count_at_least(Severity::Info), 3);
+	}
+
+	#[test]
+	fn sarif() {
+		let sarif = format::render(&report(), OutputFormat::Sarif, Severity::Error).unwrap();
+		let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+
+		let run = &sarif["runs"][0];
+		let result = &run["results"][0];
+
+		assert_eq!(sarif["version"], "2.1.0");
+		assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 3);
+		assert_eq!(result["level"], "error");
+		assert_eq!(result["ruleId"], "banned-api");
+		assert_eq!(
+			result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+			"src/init.luau"
+		);
+		assert_eq!(result["locations"][0]["physicalLocation"]["region"]["startColumn"], 11);
+		assert_eq!(run["results"][2]["level"], "note");
+	}
+
+	#[test]
+	fn junit() {
+		let errors = format::to_junit(&report(), Severity::Error);
+		let warnings = format::to_junit(&report(), Severity::Warning);
+
+		assert!(errors.contains(r#"<testsuites name="fluxo validate" tests="3" failures="1">"#));
+		assert!(warnings.contains(r#"<testsuites name="fluxo validate" tests="3" failures="2">"#));
+		assert!(errors.contains("Banned API &apos;loadstring&apos; is used"));
+
+		let empty = format::to_junit(&Report::new(vec![]), Severity::Error);
+
+		assert!(empty.contains(r#"tests="1" failures="0""#));
+	}
+}
+
+mod validator {
+	use super::common::workspace;
+	use anyhow::{bail, Result};
+	use fluxo::validation::{
+		checks::{Check, Context},
+		validator::Validator,
+		Diagnostic, Severity,
+	};
+	use std::{
+		fs,
+		path::{Path, PathBuf},
+	};
+
+	struct TodoCheck;
+
+	impl Check for TodoCheck {
+		fn name(&self) -> &'static str {
+			"todo"
+		}
+
+		fn run(&self, context: &Context) -> Result<Vec<Diagnostic>> {
+			let mut diagnostics = Vec::new();
+
+			for path in context.source_files()? {
+				if fs::read_to_string(context.workspace_dir.join(&path))?.contains("TODO") {
+					diagnostics.push(Diagnostic::new("todo", Severity::Info, "Unfinished code").with_path(&path));
+				}
+			}
+
+			Ok(diagnostics)
+		}
+	}
+
+	struct FailingCheck;
+
+	impl Check for FailingCheck {
+		fn name(&self) -> &'static str {
+			"failing"
+		}
+
+		fn run(&self, _context: &Context) -> Result<Vec<Diagnostic>> {
+			bail!("something went wrong")
+		}
+	}
+
+	fn rules(validator: &Validator, dir: &Path) -> Vec<(String, Severity)> {
+		validator
+			.run(dir)
+			.diagnostics
+			.into_iter()
+			.map(|diagnostic| (diagnostic.rule, diagnostic.severity))
+			.collect()
+	}
+
+	#[test]
+	fn default_checks() {
+		let dir = workspace(
+			"default",
+			&[
+				(
+					"plugin.meta.json",
+					r#"{"name": "Test", "description": "", "version": "1"}"#,
+				),
+				("src/init.luau", "loadstring(x)"),
+			],
+		);
+
+		assert_eq!(
+			rules(&Validator::new(), &dir),
+			vec![
+				("structure".into(), Severity::Warning),
+				("icon".into(), Severity::Warning),
+				("metadata".into(), Severity::Warning),
+				("metadata".into(), Severity::Warning),
+				("banned-api".into(), Severity::Warning),
+			]
+		);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn pluggable_checks() {
+		let dir = workspace("pluggable", &[("src/a.luau", "-- TODO"), ("src/b.lua", "")]);
+
+		let validator = Validator::empty().with_check(TodoCheck).with_check(FailingCheck);
+		let report = validator.run(&dir);
+
+		assert_eq!(validator.checks().collect::<Vec<_>>(), vec!["todo", "failing"]);
+		assert_eq!(report.summary.errors, 1);
+		assert_eq!(report.diagnostics[0].rule, "failing");
+		assert_eq!(report.diagnostics[1].path, Some(PathBuf::from("src/a.luau")));
+
+		let validator = validator.without("failing");
+
+		assert_eq!(rules(&validator, &dir), vec![("todo".into(), Severity::Info)]);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn invalid_config() {
+		let dir = workspace("config", &[("fluxo.config.json", "{")]);
+
+		assert_eq!(
+			rules(&Validator::empty(), &dir),
+			vec![("config".into(), Severity::Error)]
+		);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn cache() {
+		let dir = workspace("cache", &[("src/init.luau", "loadstring(\"\")")]);
+		let cache = dir.join(".fluxo/cache/validation.json");
+
+		let messages = |validator: Validator| -> Vec<String> {
+			let report = validator.without("structure").run(&dir);
+			report.diagnostics.into_iter().map(|d| d.message).collect()
+		};
+
+		assert_eq!(messages(Validator::new()), vec!["Banned API 'loadstring' is used"]);
+		assert!(cache.exists());
+
+		// Tamper with the cache to see whether it is used
+		let contents = fs::read_to_string(&cache).unwrap();
+		fs::write(&cache, contents.replace("Banned API", "Cached")).unwrap();
+
+		assert_eq!(messages(Validator::new()), vec!["Cached 'loadstring' is used"]);
+		assert_eq!(
+			messages(Validator::new().without_cache()),
+			vec!["Banned API 'loadstring' is used"]
+		);
+
+		// Changed content
+		fs::write(dir.join("src/init.luau"), "getfenv()").unwrap();
+		assert_eq!(messages(Validator::new()), vec!["Banned API 'getfenv' is used"]);
+
+		// Changed rules
+		let contents = fs::read_to_string(&cache).unwrap();
+		fs::write(&cache, contents.replace("Banned API", "Cached")).unwrap();
+		fs::write(dir.join("fluxo.config.json"), r#"{"validation": {"bannedApis": []}}"#).unwrap();
+		assert!(messages(Validator::new()).is_empty());
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn only_changed() {
+		let dir = workspace(
+			"only-changed",
+			&[
+				("src/init.luau", "loadstring(\"\")"),
+				("src/util/init.luau", "return 0"),
+			],
+		);
+
+		let messages = |validator: Validator| -> Vec<String> {
+			let report = validator.without("structure").run(&dir);
+			report.diagnostics.into_iter().map(|d| d.message).collect()
+		};
+
+		assert_eq!(messages(Validator::new()), vec!["Banned API 'loadstring' is used"]);
+
+		// Files that are not listed as changed are not read again
+		fs::write(dir.join("src/init.luau"), "getfenv()").unwrap();
+		fs::write(dir.join("src/util/init.luau"), "setfenv()").unwrap();
+
+		assert_eq!(
+			messages(Validator::new().only_changed(vec![PathBuf::from("src/util")])),
+			vec!["Banned API 'loadstring' is used", "Banned API 'setfenv' is used"]
+		);
+
+		assert_eq!(
+			messages(Validator::new().only_changed(vec![PathBuf::from("src/init.luau")])),
+			vec!["Banned API 'getfenv' is used", "Banned API 'setfenv' is used"]
+		);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+}
+
+mod metadata {
+	use fluxo::{
+		plugin::metadata::{normalize_version, PluginMetadata, SCHEMA},
+		validation::Severity,
+	};
+	use serde_json::Value;
+	use std::{env, path::Path};
+
+	fn metadata(json: &str) -> PluginMetadata {
+		serde_json::from_str(json).unwrap()
+	}
+
+	fn errors(json: &str) -> Vec<String> {
+		metadata(json)
+			.validate(&env::temp_dir())
+			.into_iter()
+			.filter(|diagnostic| diagnostic.severity == Severity::Error)
+			.map(|diagnostic| diagnostic.message)
+			.collect()
+	}
+
+	#[test]
+	fn versions() {
+		assert_eq!(normalize_version("1"), Some("1.0.0".into()));
+		assert_eq!(normalize_version(" v1.2 "), Some("1.2.0".into()));
+		assert_eq!(normalize_version("1.0-beta.1"), Some("1.0.0-beta.1".into()));
+		assert_eq!(normalize_version("1.2.3.4"), None);
+		assert_eq!(normalize_version("latest"), None);
+	}
+
+	#[test]
+	fn valid() {
+		let json = r#"{
+			"name": "Plugin",
+			"description": "Does things",
+			"version": "1.0.0-rc.1",
+			"tags": ["ui", "building"],
+			"authorContact": "https://example.com/me",
+			"icon": "rbxassetid://123"
+		}"#;
+
+		assert!(metadata(json).validate(Path::new(".")).is_empty());
+	}
+
+	#[test]
+	fn invalid() {
+		let long_name = "a".repeat(51);
+
+		assert_eq!(errors(r#"{"version": "1.0.0"}"#), vec!["Plugin name is required"]);
+		assert_eq!(
+			errors(&format!(r#"{{"name": "{}", "version": "1.0.0"}}"#, long_name)),
+			vec!["Plugin name is longer than 50 characters"]
+		);
+		assert_eq!(
+			errors(r#"{"name": "a", "version": "one"}"#),
+			vec!["Version 'one' is not a valid semantic version (e.g., 1.0.0)"]
+		);
+		assert_eq!(
+			errors(r#"{"name": "a", "version": "1.0.0", "authorContact": "me@localhost"}"#),
+			vec!["Author contact 'me@localhost' must be an email address or http(s) URL"]
+		);
+		assert_eq!(
+			errors(r#"{"name": "a", "version": "1.0.0", "icon": "rbxassetid://abc"}"#),
+			vec!["Icon 'rbxassetid://abc' is not a valid asset ID"]
+		);
+		assert_eq!(
+			errors(r#"{"name": "a", "version": "1.0.0", "icon": "missing.png"}"#),
+			vec!["Icon file 'missing.png' does not exist"]
+		);
+		assert!(errors(r#"{"name": "a", "version": "1.0.0", "tags": ["games"]}"#)[0].starts_with("Unknown tag 'games'"));
+	}
+
+	#[test]
+	fn fix() {
+		let mut metadata = metadata(
+			r#"{
+				"name": " Plugin ",
+				"version": "1.0",
+				"tags": ["UI", "ui", " building"],
+				"authorContact": "me@example.com ",
+				"category": "Utility"
+			}"#,
+		);
+
+		assert_eq!(metadata.fix().len(), 6);
+		assert_eq!(metadata.name, "Plugin");
+		assert_eq!(metadata.version, "1.0.0");
+		assert_eq!(metadata.tags, vec!["ui", "building"]);
+		assert_eq!(metadata.author_contact.as_deref(), Some("me@example.com"));
+		assert_eq!(metadata.extra["category"], "Utility");
+		assert!(metadata.fix().is_empty());
+	}
+
+	#[test]
+	fn schema() {
+		let schema: Value = serde_json::from_str(SCHEMA).unwrap();
+		let properties = schema["properties"].as_object().unwrap();
+
+		let metadata = PluginMetadata {
+			schema: Some(String::new()),
+			author: Some(String::new()),
+			author_contact: Some(String::new()),
+			icon: Some(String::new()),
+			..Default::default()
+		};
+
+		let fields = serde_json::to_value(metadata).unwrap();
+		let fields = fields.as_object().unwrap();
+
+		assert_eq!(fields.keys().collect::<Vec<_>>(), properties.keys().collect::<Vec<_>>());
+		assert_eq!(
+			schema["properties"]["tags"]["items"]["enum"].as_array().unwrap().len(),
+			fluxo::plugin::metadata::ALLOWED_TAGS.len()
+		);
+	}
+}
+
+mod requires {
+	use fluxo::validation::requires::{analyze_dom, Analysis, CYCLE_RULE, NON_MODULE_RULE, UNRESOLVED_RULE};
+	use rbx_dom_weak::{InstanceBuilder, WeakDom};
+
+	fn module(name: &str, source: &str) -> InstanceBuilder {
+		InstanceBuilder::new("ModuleScript")
+			.with_name(name)
+			.with_property("Source", source)
+	}
+
+	fn analyze(root: InstanceBuilder) -> Analysis {
+		analyze_dom(&WeakDom::new(root), |_| None)
+	}
+
+	fn rules(analysis: &Analysis) -> Vec<&str> {
+		analysis.diagnostics.iter().map(|d| d.rule.as_str()).collect()
+	}
+
+	#[test]
+	fn resolved() {
+		let analysis = analyze(
+			InstanceBuilder::new("Folder").with_name("Plugin").with_children([
+				module(
+					"Main",
+					"local Util = require(script.Parent.Util)\nlocal Str = require(\"./Lib/Str\")",
+				),
+				module(
+					"Util",
+					"return require(script.Parent:WaitForChild(\"Lib\", 5)[\"Str\"])",
+				),
+				InstanceBuilder::new("Folder")
+					.with_name("Lib")
+					.with_child(module("Str", "return {}")),
+			]),
+		);
+
+		assert!(analysis.diagnostics.is_empty(), "{:?}", analysis.diagnostics);
+		assert_eq!(analysis.graph.nodes.len(), 3);
+		assert_eq!(analysis.graph.edges.len(), 3);
+		assert_eq!(analysis.graph.nodes[0].name, "Plugin.Main");
+	}
+
+	#[test]
+	fn unresolved_and_non_module() {
+		let analysis = analyze(
+			InstanceBuilder::new("Folder").with_name("Plugin").with_children([
+				module(
+					"Main",
+					"require(script.Parent.Missing)\nrequire(script.Parent.Server)\nrequire(path)",
+				),
+				InstanceBuilder::new("Script")
+					.with_name("Server")
+					.with_property("Source", ""),
+			]),
+		);
+
+		assert_eq!(rules(&analysis), vec![UNRESOLVED_RULE, NON_MODULE_RULE]);
+
+		let unresolved = &analysis.diagnostics[0];
+		assert_eq!((unresolved.line, unresolved.column), (Some(1), Some(1)));
+		assert!(unresolved.message.contains("'Plugin' has no child named 'Missing'"));
+	}
+
+	#[test]
+	fn services() {
+		let analysis = analyze(InstanceBuilder::new("DataModel").with_children([
+			InstanceBuilder::new("ReplicatedStorage").with_child(module("Shared", "return {}")),
+			InstanceBuilder::new("ServerScriptService").with_child(
+				InstanceBuilder::new("Script").with_name("Main").with_property(
+					"Source",
+					"require(game:GetService(\"ReplicatedStorage\").Shared)\n\
+					require(game:GetService(\"ReplicatedStorage\").Other)\n\
+					require(game:GetService(\"Players\").LocalPlayer)",
+				),
+			),
+		]));
+
+		assert_eq!(rules(&analysis), vec![UNRESOLVED_RULE]);
+		assert_eq!(analysis.diagnostics[0].line, Some(2));
+		assert_eq!(analysis.graph.edges.len(), 1);
+	}
+
+	#[test]
+	fn cycles() {
+		let analysis = analyze(InstanceBuilder::new("Folder").with_name("Plugin").with_children([
+			module("A", "return require(script.Parent.B)"),
+			module("B", "return require(script.Parent.C)"),
+			module("C", "return require(script.Parent.A)"),
+			module("D", "return require(script.Parent.A)"),
+		]));
+
+		assert_eq!(rules(&analysis), vec![CYCLE_RULE]);
+		assert_eq!(
+			analysis.diagnostics[0].message,
+			"Require cycle: Plugin.A -> Plugin.B -> Plugin.C -> Plugin.A"
+		);
+
+		// Cycles sharing modules that were already visited are all reported
+		let analysis = analyze(InstanceBuilder::new("Folder").with_name("Plugin").with_children([
+			module("A", "require(script.Parent.B)\nrequire(script.Parent.C)"),
+			module("B", "return require(script.Parent.A)"),
+			module("C", "return require(script.Parent.B)"),
+		]));
+
+		let messages: Vec<&str> = analysis.diagnostics.iter().map(|d| d.message.as_str()).collect();
+
+		assert_eq!(
+			messages,
+			vec![
+				"Require cycle: Plugin.A -> Plugin.B -> Plugin.A",
+				"Require cycle: Plugin.A -> Plugin.C -> Plugin.B -> Plugin.A",
+			]
+		);
+	}
+
+	#[test]
+	fn export() {
+		let analysis = analyze(InstanceBuilder::new("Folder").with_name("Plugin").with_children([
+			module("Main", "require(script.Parent.Util)\nrequire(script.Parent.Util)"),
+			module("Util", "return {}"),
+		]));
+
+		assert_eq!(
+			analysis.graph.to_dot(),
+			"digraph requires {\n\trankdir=LR;\n\tnode [shape=box];\n\
+			\tn0 [label=\"Plugin.Main\"];\n\tn1 [label=\"Plugin.Util\"];\n\tn0 -> n1;\n}\n"
+		);
+		assert_eq!(
+			analysis.graph.to_mermaid(),
+			"graph LR\n\tn0[\"Plugin.Main\"]\n\tn1[\"Plugin.Util\"]\n\tn0 --> n1\n"
+		);
+	}
+}
+
+mod properties {
+	use fluxo::{
+		resolution::UnresolvedValue,
+		validation::properties::{
+			check_class, check_property, DEPRECATED_RULE, NOT_SCRIPTABLE_RULE, TYPE_MISMATCH_RULE, UNKNOWN_CLASS_RULE,
+			UNKNOWN_PROPERTY_RULE,
+		},
+	};
+
+	fn check(class: &str, property: &str, value: &str) -> Vec<(String, String)> {
+		let value: UnresolvedValue = serde_json::from_str(value).unwrap();
+
+		check_property(class, property, &value)
+			.into_iter()
+			.map(|diagnostic| (diagnostic.rule, diagnostic.message))
+			.collect()
+	}
+
+	#[test]
+	fn classes() {
+		assert!(check_class("Part").is_empty());
+
+		let diagnostics = check_class("Modle");
+		assert_eq!(diagnostics[0].rule, UNKNOWN_CLASS_RULE);
+		assert_eq!(diagnostics[0].message, "Unknown class Modle, did you mean Model?");
+	}
+
+	#[test]
+	fn valid() {
+		assert!(check("Part", "Transparency", "0.5").is_empty());
+		assert!(check("Part", "Anchored", "true").is_empty());
+		assert!(check("Frame", "Size", "[[1, 0], [0, 50]]").is_empty());
+		assert!(check("UnknownClass", "Whatever", "1").is_empty());
+	}
+
+	#[test]
+	fn unknown() {
+		assert_eq!(
+			check("Part", "Trasparency", "0.5"),
+			vec![(
+				UNKNOWN_PROPERTY_RULE.into(),
+				"Unknown property Part.Trasparency, did you mean Transparency?".into()
+			)]
+		);
+		assert_eq!(
+			check("Part", "Completely", "1"),
+			vec![(UNKNOWN_PROPERTY_RULE.into(), "Unknown property Part.Completely".into())]
+		);
+	}
+
+	#[test]
+	fn mismatch() {
+		let diagnostics = check("Part", "Anchored", "\"yes\"");
+
+		assert_eq!(diagnostics.len(), 1);
+		assert_eq!(diagnostics[0].0, TYPE_MISMATCH_RULE);
+		assert!(diagnostics[0].1.contains("Expected Bool, got a string"));
+	}
+
+	#[test]
+	fn deprecated_and_not_scriptable() {
+		let rules = |diagnostics: Vec<(String, String)>| diagnostics.into_iter().map(|d| d.0).collect::<Vec<_>>();
+
+		assert_eq!(
+			rules(check("Part", "brickColor", "\"Bright red\"")),
+			vec![DEPRECATED_RULE]
+		);
+		assert!(check("Part", "brickColor", "\"Bright red\"")[0]
+			.1
+			.ends_with("use Color instead"));
+		assert_eq!(rules(check("Part", "size", "[1, 2, 3]")), vec![NOT_SCRIPTABLE_RULE]);
+	}
+}
+
+mod budgets {
+	use fluxo::{
+		plugin::config::BudgetConfig,
+		validation::{
+			budgets::{check, Usage, INSTANCES_RULE, LOC_RULE, RULE, SIZE_RULE, SOURCE_RULE},
+			Severity,
+		},
+	};
+	use rbx_dom_weak::{InstanceBuilder, WeakDom};
+	use std::path::PathBuf;
+
+	fn dom() -> WeakDom {
+		WeakDom::new(
+			InstanceBuilder::new("Folder").with_name("Plugin").with_children([
+				InstanceBuilder::new("Script")
+					.with_name("Main")
+					.with_property("Source", "print(1)\nprint(2)\nprint(3)"),
+				InstanceBuilder::new("Folder").with_name("Lib").with_children([
+					InstanceBuilder::new("ModuleScript")
+						.with_name("Big")
+						.with_property("Source", "local x = 1\n".repeat(20)),
+					InstanceBuilder::new("Folder").with_name("Empty"),
+				]),
+			]),
+		)
+	}
+
+	fn run(budgets: BudgetConfig) -> (Usage, Vec<(String, Severity, String)>) {
+		let dom = dom();
+		let (usage, diagnostics) = check(&dom, &[dom.root_ref()], &budgets, |_| Some(PathBuf::from("src"))).unwrap();
+
+		let diagnostics = diagnostics
+			.into_iter()
+			.map(|diagnostic| (diagnostic.rule, diagnostic.severity, diagnostic.message))
+			.collect();
+
+		(usage, diagnostics)
+	}
+
+	#[test]
+	fn within() {
+		let (usage, diagnostics) = run(BudgetConfig::default());
+
+		assert_eq!(usage.instances, 5);
+		assert_eq!(usage.loc, 23);
+		assert!(usage.size > 0);
+
+		assert_eq!(diagnostics.len(), 1);
+		assert_eq!(diagnostics[0].0, RULE);
+		assert_eq!(diagnostics[0].1, Severity::Info);
+	}
+
+	#[test]
+	fn exceeded() {
+		let (_, diagnostics) = run(BudgetConfig {
+			max_source_length: 100,
+			max_instances: 4,
+			max_loc: 25,
+			max_size: 1,
+		});
+
+		let rules: Vec<(&str, Severity)> = diagnostics.iter().map(|d| (d.0.as_str(), d.1)).collect();
+
+		assert_eq!(
+			rules,
+			vec![
+				(SOURCE_RULE, Severity::Error),
+				(INSTANCES_RULE, Severity::Error),
+				(LOC_RULE, Severity::Warning),
+				(SIZE_RULE, Severity::Error),
+				(RULE, Severity::Info),
+			]
+		);
+
+		assert_eq!(
+			diagnostics[0].2,
+			"Script Plugin.Lib.Big is 240 characters long, the limit is 100"
+		);
+		assert_eq!(
+			diagnostics[1].2,
+			"Plugin has 5 instances, the limit is 4, largest: Plugin.Lib (3), Plugin.Main (1)"
+		);
+		assert_eq!(
+			diagnostics[2].2,
+			"Plugin has 23 lines of code, close to the limit of 25, largest: Plugin.Lib.Big (20), Plugin.Main (3)"
+		);
+		assert!(diagnostics[3].2.contains("largest: Plugin.Lib ("));
+	}
+}